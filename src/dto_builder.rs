@@ -1,15 +1,15 @@
-use std::{
-    collections::{HashMap, HashSet},
-    mem,
-};
+use std::collections::{HashMap, HashSet};
 
 use ::syn::parse_str;
-use proc_macro2::TokenStream;
+use proc_macro2::{Group, Span, TokenStream, TokenTree};
 use quote::{format_ident, quote};
 
+use syn::Attribute;
+
 use crate::{
-    mapper_entry::{MapValue, MapperEntry},
-    struct_entry::{FieldEntry, StructEntry},
+    mapper_entry::{MapValue, MapperEntry, NewField, PRIVATE_VIS},
+    struct_entry::{is_type_copy, FieldEntry, StructEntry},
+    utils::{glob_match, is_glob, remove_white_space},
 };
 
 //this is to generate the dto structure along with the fields
@@ -17,61 +17,143 @@ pub fn generate_dto_stream(
     mapper_entries: &Vec<MapperEntry>,
     struct_entry: &StructEntry,
 ) -> Vec<TokenStream> {
-    let dtos = mapper_entries.iter().map(|mapper_entry| {
-        let mappings = build_fields(&struct_entry, &mapper_entry);
-        let dto = format_ident!("{}", mapper_entry.dto.as_str());
+    //a dto mapped with generate=false is an existing type
+    let dtos = mapper_entries
+        .iter()
+        .filter(|mp| mp.generate)
+        .map(|mapper_entry| {
+            let mappings = build_fields(&struct_entry, &mapper_entry);
+            let docs = build_dto_docs(struct_entry, mapper_entry);
+            build_dto_struct(mapper_entry, &mappings, &docs)
+        });
 
-        let derive_idents: Vec<syn::Ident> = mapper_entry
-            .derive
-            .iter()
-            .map(|derive| {
-                let ident: syn::Ident = format_ident!("{}", derive.as_str());
-                ident
-            })
-            .collect();
+    dtos.collect()
+}
 
-        let macro_attr: Vec<_> = mapper_entry
-            .macro_attr
-            .iter()
-            .filter_map(|attr_str| {
-                let stripped = attr_str.trim_start_matches("#[").trim_end_matches("]");
-                if let Ok(meta) = syn::parse_str::<syn::Meta>(stripped) {
-                    Some(syn::Attribute {
-                        pound_token: syn::Token![#](proc_macro2::Span::call_site()),
-                        style: syn::AttrStyle::Outer,
-                        bracket_token: syn::token::Bracket(proc_macro2::Span::call_site()),
-                        meta,
-                    })
-                } else {
-                    None
-                }
-            })
-            .collect();
-        // eprintln!("==================>");
-        // eprintln!("source_macro_attr={:#?}", mapper_entry.macro_attr);
-        // eprintln!("parsed_macro_attr={:#?}", macro_attr);
-        if !mapper_entry.no_builder {
-            return quote! {
-                #[derive( #(#derive_idents),* )]
-                #[builder(default)]
-                #(#macro_attr)*
-                pub struct #dto {
-                    #(#mappings),*
-                }
-            };
-        }
+//the dto structure with its derives, attributes and docs around the given fields
+pub fn build_dto_struct(
+    mapper_entry: &MapperEntry,
+    mappings: &[TokenStream],
+    docs: &[String],
+) -> TokenStream {
+    let dto = format_ident!("{}", mapper_entry.dto.as_str());
+
+    let derive_idents: Vec<syn::Ident> = mapper_entry
+        .derive
+        .iter()
+        .map(|derive| {
+            let ident: syn::Ident = format_ident!("{}", derive.as_str());
+            ident
+        })
+        .collect();
 
-        //if no_builder=true return without a builder
+    let macro_attr: Vec<_> = mapper_entry
+        .macro_attr
+        .iter()
+        .filter_map(|attr_str| {
+            let stripped = attr_str.trim_start_matches("#[").trim_end_matches("]");
+            if let Ok(meta) = syn::parse_str::<syn::Meta>(stripped) {
+                Some(syn::Attribute {
+                    pound_token: syn::Token![#](proc_macro2::Span::call_site()),
+                    style: syn::AttrStyle::Outer,
+                    bracket_token: syn::token::Bracket(proc_macro2::Span::call_site()),
+                    meta,
+                })
+            } else {
+                None
+            }
+        })
+        .collect();
+    // eprintln!("==================>");
+    // eprintln!("source_macro_attr={:#?}", mapper_entry.macro_attr);
+    // eprintln!("parsed_macro_attr={:#?}", macro_attr);
+    let cfg = get_entry_cfg(mapper_entry);
+    if !mapper_entry.no_builder {
         return quote! {
-             #[derive( #(#derive_idents),* )]
+            #cfg
+            #(#[doc = #docs])*
+            #[derive( #(#derive_idents),* )]
+            #[builder(default)]
             #(#macro_attr)*
             pub struct #dto {
                 #(#mappings),*
             }
         };
+    }
+
+    //if no_builder=true return without a builder
+    return quote! {
+        #cfg
+        #(#[doc = #docs])*
+         #[derive( #(#derive_idents),* )]
+        #(#macro_attr)*
+        pub struct #dto {
+            #(#mappings),*
+        }
+    };
+}
+
+//this is to document the dto with the entry doc followed by a section describing how it is mapped
+fn build_dto_docs(st_entry: &StructEntry, mp_entry: &MapperEntry) -> Vec<String> {
+    let mut docs: Vec<String> = Vec::new();
+    if let Some(ref doc) = mp_entry.doc {
+        doc.lines()
+            .for_each(|line| docs.push(format!(" {}", line.trim())));
+        docs.push(String::new());
+    }
+
+    docs.push(format!(
+        " Data transfer object generated by dto_mapper from `{}`.",
+        st_entry.name
+    ));
+    docs.push(String::new());
+
+    docs.extend(build_field_docs(
+        st_entry,
+        &get_dto_fields(st_entry, mp_entry),
+    ));
+
+    mp_entry.new_fields.iter().for_each(|new_field| {
+        docs.push(format!(
+            " - `{}`: new field computed with `{}`",
+            new_field.field_name, new_field.expression_value
+        ));
     });
 
-    dtos.collect()
+    docs
+}
+
+//one line per dto field describing the struct field it is mapped from
+pub fn build_field_docs(st_entry: &StructEntry, dto_fields: &[DtoField]) -> Vec<String> {
+    dto_fields
+        .iter()
+        .map(|dto_field| {
+            let mut line = format!(
+                " - `{}`: mapped from `{}::{}`",
+                dto_field.name, st_entry.name, dto_field.source.field_name
+            );
+            if dto_field.name != dto_field.source.field_name {
+                line.push_str(", renamed");
+            }
+            if dto_field.is_wrapped() {
+                line.push_str(", wrapped into `Option`");
+            }
+            if dto_field.is_unwrapped() {
+                line.push_str(", unwrapped from `Option`");
+            }
+            if let Some(MapValue {
+                convert: Some(ref converter),
+                ..
+            }) = dto_field.map_value
+            {
+                line.push_str(&format!(", converted with `{}`", converter));
+            }
+            if dto_field.is_fanout {
+                line.push_str(", cloned");
+            }
+            line
+        })
+        .collect()
 }
 
 //this is to build the implementation of Into trait for Dto and original structure
@@ -89,12 +171,51 @@ pub fn generate_impl(
             }
 
             let impl_stream: TokenStream;
-            let struct_name = format_ident!("{}", &struct_entry.name.as_str());
-            let dto = format_ident!("{}", mp_entry.dto.as_str());
+            let struct_name = struct_entry.path();
+            let dto = get_dto_path(mp_entry);
+            let cfg = get_entry_cfg(mp_entry);
 
-            if is_dto {
+            if is_dto && is_fallible(struct_entry, mp_entry) {
+                //a struct with None in a field unwrapped without a fallback can't be converted.
+                //self of the field values and new fields expressions is the converted value
+                let error = get_conversion_error_ident(mp_entry);
+                let init_fields =
+                    replace_self(quote! { #(#init_fields),* }, &format_ident!("value"));
+                let message = format!("missing value for field `{{}}` of {}", mp_entry.dto);
+                impl_stream = quote! {
+                    #cfg
+                    #[derive(Debug, Clone, PartialEq, Eq)]
+                    pub enum #error {
+                        MissingField(&'static str),
+                    }
+
+                    #cfg
+                    impl ::std::fmt::Display for #error {
+                        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                            match self {
+                                #error::MissingField(field) => write!(f, #message, field),
+                            }
+                        }
+                    }
+
+                    #cfg
+                    impl ::std::error::Error for #error {}
+
+                    #cfg
+                    impl TryFrom<#struct_name> for #dto {
+                        type Error = #error;
+
+                        fn try_from(value: #struct_name) -> Result<Self, Self::Error> {
+                            Ok(#dto {
+                                #init_fields
+                            })
+                        }
+                    }
+                };
+            } else if is_dto {
                 //convert struct into dto
                 impl_stream = quote! {
+                    #cfg
                     impl Into<#dto> for #struct_name{
                         fn into(self) -> #dto {
                             #dto {
@@ -105,19 +226,36 @@ pub fn generate_impl(
                 };
             } else {
                 //convert dto into original struct
+                //fields missing from the dto are initialized with the default value of the struct
+                let default_fields = if init_fields.len() < struct_entry.field_entries.len() {
+                    quote! { ..#struct_name::default() }
+                } else {
+                    quote! {}
+                };
+                let dto_self = if mp_entry.zeroize.is_empty() {
+                    quote! { self }
+                } else {
+                    quote! { mut self }
+                };
                 impl_stream = quote! {
+                    #cfg
                     impl Into<#struct_name> for #dto{
-                        fn into(self) -> #struct_name {
+                        fn into(#dto_self) -> #struct_name {
                             #struct_name {
-                                #(#init_fields),* ,
-                                ..#struct_name::default()
+                                #(#init_fields,)*
+                                #default_fields
                             }
                         }
                     }
                 };
             }
             //println!("#######dto_impls = {}",impl_stream.to_string());
-            impl_stream
+            if mp_entry.generate {
+                impl_stream
+            } else {
+                //a field missing from the target type or with another type is reported on the mapper attribute
+                respan(impl_stream, mp_entry.span.unwrap_or_else(Span::call_site))
+            }
         })
         .collect();
 
@@ -130,110 +268,394 @@ fn build_into_fields(
     mp_entry: &MapperEntry,
     is_dto: bool,
 ) -> Vec<TokenStream> {
-    //we retrieve a hashmap of MapValue with key=source_field_name in the struct , and the the value as MapValue
-    let map_fields = get_map_of_mapvalue(mp_entry);
+    let dto_fields = get_dto_fields(st_entry, mp_entry);
+    let dto_fields = match is_dto {
+        true => get_clones_first(dto_fields),
+        false => dto_fields
+            .into_iter()
+            .filter(|dto_field| dto_field.is_reversible())
+            .collect(),
+    };
+    dto_fields
+        .iter()
+        .map(|dto_field| {
+            // the dto_name is the target dto field name which may have been renamed
+            // the struct_name is the source field name in the struct
+            let dto_name = dto_field.ident();
+            let struct_name = dto_field.source_ident();
 
-    // Let us retrieve the ignore fields
-    let ignore_fields = get_ignore_fields(mp_entry);
+            // if m_value.required = false(Option) , field.is_optional = false (straight_value)
+            let is_optional = dto_field.is_wrapped();
+            let cfg_attrs = dto_field.cfg_attributes();
+
+            if dto_field.is_unwrapped() {
+                return build_unwrapped_into_field(dto_field, mp_entry, is_dto);
+            }
+
+            if is_dto {
+                let value = dto_field.convert_value(dto_field.read_source(quote! { self }));
+                quote! { #(#cfg_attrs)* #dto_name: #value }
+            } else if is_optional {
+                let dto_value = get_dto_value(quote! { self }, &dto_name, mp_entry);
+                quote! { #(#cfg_attrs)* #struct_name: #dto_value.unwrap_or_default() }
+            } else {
+                let dto_value = get_dto_value(quote! { self }, &dto_name, mp_entry);
+                quote! { #(#cfg_attrs)* #struct_name: #dto_value }
+            }
+        })
+        .collect()
+}
+
+//a dto implementing Drop to zeroize its fields can't be moved out of, so its values are taken instead
+pub fn get_dto_value(
+    dto: TokenStream,
+    dto_name: &syn::Ident,
+    mp_entry: &MapperEntry,
+) -> TokenStream {
+    if mp_entry.zeroize.is_empty() {
+        quote! { #dto.#dto_name }
+    } else {
+        quote! { ::core::mem::take(&mut #dto.#dto_name) }
+    }
+}
+
+//an Option<T> struct field is unwrapped into the dto, and wrapped back into Some when converting the dto
+fn build_unwrapped_into_field(
+    dto_field: &DtoField,
+    mp_entry: &MapperEntry,
+    is_dto: bool,
+) -> TokenStream {
+    let dto_name = dto_field.ident();
+    let struct_name = dto_field.source_ident();
+    let cfg_attrs = dto_field.cfg_attributes();
 
-    // let selected_fields =
-    //   get_selected_fields(&st_entry, &ignore_fields, &map_fields);
-    let selected_fields = extract_selected_fields(&st_entry, mp_entry, &map_fields, &ignore_fields);
+    if !is_dto {
+        let dto_value = get_dto_value(quote! { self }, &dto_name, mp_entry);
+        return quote! { #(#cfg_attrs)* #struct_name: Some(#dto_value) };
+    }
 
-    selected_fields
+    let value = dto_field.read_source(quote! { self });
+    match dto_field.unwrap_or_expr() {
+        Some(fallback) => {
+            quote! { #(#cfg_attrs)* #dto_name: #value.unwrap_or_else(|| #fallback) }
+        }
+        None => {
+            let error = get_conversion_error_ident(mp_entry);
+            let field = dto_field.source.field_name.as_str();
+            quote! { #(#cfg_attrs)* #dto_name: #value.ok_or(#error::MissingField(#field))? }
+        }
+    }
+}
+
+//conversion into a dto is fallible when a field is unwrapped without unwrap_or fallback
+fn is_fallible(st_entry: &StructEntry, mp_entry: &MapperEntry) -> bool {
+    get_dto_fields(st_entry, mp_entry)
         .iter()
-        .map(|field| {
-            let mut name = format!("{}", field.field_name.to_string());
-            //first we assume that is_dto = true  (building initialization macro for dto init field for Into trait)
-            // build fields for initialization such that #left_name = self.#right_name
-            //the right_name contains the struct field value
-            let mut right_name = format_ident!("{}", name.as_str());
-            // the left_name has the target dto field_name which will hold the value of right_name;
-            let mut left_name = right_name.clone();
-            //Let's check if the dto field(left_name) has been renamed
-            if let Some(m_value) = map_fields.get(&name) {
-                //let's rename the struct field if there is a mapping for it
-                if let Some(ref new_name) = m_value.to_field {
-                    name = new_name.clone();
-                    left_name = format_ident!("{}", name.as_str());
-                }
+        .any(|dto_field| dto_field.is_fallible())
+}
 
-                //if build into is not for a dto but for a struct, let's swap left and right
-                if !is_dto {
-                    mem::swap(&mut right_name, &mut left_name);
-                }
+fn get_conversion_error_ident(mp_entry: &MapperEntry) -> syn::Ident {
+    format_ident!("{}ConversionError", mp_entry.dto_name())
+}
+
+//the dto type, which is a path for a target type such as api::LoginPayload
+pub fn get_dto_path(mp_entry: &MapperEntry) -> syn::Path {
+    parse_str(&mp_entry.dto)
+        .unwrap_or_else(|error| panic!("Failed to parse dto name '{}' : {}", mp_entry.dto, error))
+}
+
+//replaces the self keyword with an identifier, in nested groups as well
+fn replace_self(tokens: TokenStream, replacement: &syn::Ident) -> TokenStream {
+    tokens
+        .into_iter()
+        .map(|token| match token {
+            TokenTree::Group(group) => {
+                let mut replaced =
+                    Group::new(group.delimiter(), replace_self(group.stream(), replacement));
+                replaced.set_span(group.span());
+                TokenTree::Group(replaced)
+            }
+            TokenTree::Ident(ident) if ident == "self" => TokenTree::Ident(replacement.clone()),
+            token => token,
+        })
+        .collect()
+}
+
+//the tokens are located at the span while still being marked as generated by the macro
+fn respan(tokens: TokenStream, span: Span) -> TokenStream {
+    let span = Span::call_site().located_at(span);
+    tokens
+        .into_iter()
+        .map(|token| match token {
+            TokenTree::Group(group) => {
+                let mut respanned = Group::new(group.delimiter(), respan(group.stream(), span));
+                respanned.set_span(span);
+                TokenTree::Group(respanned)
+            }
+            mut token => {
+                token.set_span(span);
+                token
+            }
+        })
+        .collect()
+}
+
+//A DtoField binds a selected struct field to the field it results into in the dto
+#[derive(Clone)]
+pub struct DtoField {
+    pub source: FieldEntry,
+    pub map_value: Option<MapValue>,
+    //name of the field in the dto after renaming
+    pub name: String,
+    //a struct field mapped several times is cloned into the fields which aren't its primary one
+    pub is_fanout: bool,
+}
+
+impl DtoField {
+    pub fn ident(&self) -> syn::Ident {
+        format_ident!("{}", self.name.as_str())
+    }
+
+    pub fn source_ident(&self) -> syn::Ident {
+        format_ident!("{}", self.source.field_name.as_str())
+    }
+
+    //a struct field which isn't an Option is wrapped into an Option when mapped with required=false
+    pub fn is_wrapped(&self) -> bool {
+        match self.map_value {
+            Some(ref m_value) => !m_value.required && !self.source.is_optional,
+            None => false,
+        }
+    }
+
+    //cfg attributes of the struct field along with the cfg predicate of the mapped field
+    pub fn cfg_attributes(&self) -> Vec<TokenStream> {
+        let mut cfg_attrs: Vec<TokenStream> = self
+            .source
+            .cfg_attributes()
+            .iter()
+            .map(|attr| quote! { #attr })
+            .collect();
+        if let Some(MapValue {
+            cfg: Some(ref predicate),
+            ..
+        }) = self.map_value
+        {
+            cfg_attrs.push(build_cfg_attribute(predicate));
+        }
+        cfg_attrs
+    }
+
+    //an Option<T> struct field mapped with the unwrap option results into a T dto field
+    pub fn is_unwrapped(&self) -> bool {
+        match self.map_value {
+            Some(ref m_value) => m_value.unwrap && self.source.is_optional,
+            None => false,
+        }
+    }
 
-                // if m_value.required = false(Option) , field.is_optional = false (straight_value)
-                let is_optional = !m_value.required && !field.is_optional;
+    //an unwrapped field without unwrap_or fallback fails to convert when the struct field is None
+    pub fn is_fallible(&self) -> bool {
+        self.is_unwrapped()
+            && self
+                .map_value
+                .as_ref()
+                .is_some_and(|m_value| m_value.unwrap_or.is_none())
+    }
+
+    //expression used for an unwrapped field when the struct field is None
+    pub fn unwrap_or_expr(&self) -> Option<syn::Expr> {
+        let fallback = self.map_value.as_ref()?.unwrap_or.as_ref()?;
+        Some(parse_str(fallback).unwrap_or_else(|error| {
+            panic!(
+                r#"Failed to parse unwrap_or expression "{}" of field '{}' : {}"#,
+                fallback, self.source.field_name, error
+            )
+        }))
+    }
+
+    pub fn is_copy(&self) -> bool {
+        match (self.convert_type(), &self.source.inner_type) {
+            (Some(ref ty), _) if !self.is_wrapped() => is_type_copy(ty),
+            (Some(_), _) => false,
+            (None, Some(ref inner_type)) if self.is_unwrapped() => is_type_copy(inner_type),
+            _ => self.source.is_copy(),
+        }
+    }
 
-                if is_dto && is_optional {
-                    return quote! { #left_name: Some(self.#right_name) };
-                } else if !is_dto && is_optional {
-                    return quote! { #left_name: self.#right_name.unwrap_or_default() };
+    //function converting the struct field into the dto field
+    pub fn converter(&self) -> Option<syn::Path> {
+        let converter = self.map_value.as_ref()?.convert.as_ref()?;
+        Some(parse_str(converter).unwrap_or_else(|error| {
+            panic!(
+                r#"Failed to parse convert function "{}" of field '{}' : {}"#,
+                converter, self.source.field_name, error
+            )
+        }))
+    }
+
+    //type returned by the convert function when it differs from the struct field type
+    fn convert_type(&self) -> Option<syn::Type> {
+        let ty = self.map_value.as_ref()?.ty.as_ref()?;
+        Some(parse_str(ty).unwrap_or_else(|error| {
+            panic!(
+                r#"Failed to parse type "{}" of field '{}' : {}"#,
+                ty, self.name, error
+            )
+        }))
+    }
+
+    //only the primary field of a struct field is converted back into it, a converted value can't be
+    pub fn is_reversible(&self) -> bool {
+        !self.is_fanout && self.converter().is_none()
+    }
+
+    //the struct field read from the value being converted, cloned when it is fanned out
+    pub fn read_source(&self, value: TokenStream) -> TokenStream {
+        let source_name = self.source_ident();
+        if self.is_fanout {
+            quote! { #value.#source_name.clone() }
+        } else {
+            quote! { #value.#source_name }
+        }
+    }
+
+    //the struct field value passed to the convert function then wrapped into Some if needed
+    pub fn convert_value(&self, value: TokenStream) -> TokenStream {
+        let value = match self.converter() {
+            Some(converter) => quote! { #converter(#value) },
+            None => value,
+        };
+        if self.is_wrapped() {
+            quote! { Some(#value) }
+        } else {
+            value
+        }
+    }
+
+    //the field of another dto of the struct holding the same value.
+    //A converted or fanned out field can only be found by its name and type
+    pub fn find_counterpart<'a>(&self, dto_fields: &'a [DtoField]) -> Option<&'a DtoField> {
+        dto_fields.iter().find(|dto_field| {
+            dto_field.source.field_name == self.source.field_name
+                && match self.is_reversible() {
+                    true => dto_field.is_reversible(),
+                    false => {
+                        dto_field.name == self.name
+                            && dto_field.dto_type().to_string() == self.dto_type().to_string()
+                    }
                 }
+        })
+    }
+
+    pub fn dto_type(&self) -> TokenStream {
+        let ty = match self.convert_type() {
+            Some(ty) => quote! { #ty },
+            None => {
+                let ty = &self.source.field_type;
+                quote! { #ty }
             }
+        };
+        if self.is_wrapped() {
+            quote! { Option<#ty> }
+        } else if let (true, Some(inner_type)) = (self.is_unwrapped(), &self.source.inner_type) {
+            quote! { #inner_type }
+        } else {
+            quote! { #ty }
+        }
+    }
+}
 
-            quote! { #left_name: self.#right_name}
+//list of the fields of a dto in the same order they are declared in the struct
+pub fn get_dto_fields(st_entry: &StructEntry, mp_entry: &MapperEntry) -> Vec<DtoField> {
+    //we retrieve a hashmap of MapValue with key=source_field_name in the struct , and the the value as MapValue
+    let map_fields = get_map_of_mapvalue(st_entry, mp_entry);
+
+    // Let us retrieve the ignore fields
+    let ignore_fields = get_ignore_fields(st_entry, mp_entry);
+
+    extract_selected_fields(st_entry, mp_entry, &map_fields, &ignore_fields)
+        .into_iter()
+        .flat_map(|field| {
+            let map_value = map_fields.get(&field.field_name).cloned();
+            //let's rename the struct field if there is a mapping for it
+            let name = match map_value {
+                Some(ref m_value) => m_value.dto_name().to_string(),
+                None => field.field_name.clone(),
+            };
+            //the fanned out fields follow the field they are cloned from
+            let fanout_fields: Vec<DtoField> = map_value
+                .iter()
+                .flat_map(|primary| get_fanout_values(mp_entry, primary))
+                .map(|m_value| DtoField {
+                    source: field.clone(),
+                    map_value: Some(m_value.clone()),
+                    name: m_value.dto_name().to_string(),
+                    is_fanout: true,
+                })
+                .collect();
+            let mut dto_fields = vec![DtoField {
+                source: field,
+                map_value,
+                name,
+                is_fanout: false,
+            }];
+            dto_fields.extend(fanout_fields);
+            dto_fields
         })
         .collect()
 }
 
+//the fanned out fields clone the struct field before the primary field moves it
+pub fn get_clones_first(mut dto_fields: Vec<DtoField>) -> Vec<DtoField> {
+    dto_fields.sort_by_key(|dto_field| !dto_field.is_fanout);
+    dto_fields
+}
+
 fn extract_selected_fields(
     st_entry: &StructEntry,
     mp_entry: &MapperEntry,
     map_fields: &HashMap<String, MapValue>,
     ignore_fields: &HashSet<String>,
 ) -> Vec<FieldEntry> {
-    if mp_entry.exactly && map_fields.len() == 0 && ignore_fields.len() == 0 {
+    if mp_entry.exactly && map_fields.len() == 0 && !has_exclusions(mp_entry) {
         get_all_fields(&st_entry)
     } else {
-        get_selected_fields(&st_entry, &ignore_fields, &map_fields)
+        get_selected_fields(&st_entry, mp_entry, &ignore_fields, &map_fields)
     }
 }
 
-fn build_fields(st_entry: &StructEntry, mp_entry: &MapperEntry) -> Vec<TokenStream> {
-    //we retrieve a hashmap of MapValue with key=source_field_name in the struct , and the the value as MapValue
-    let map_fields = get_map_of_mapvalue(mp_entry);
-
-    // Let us retrieve the ignore fields
-    let ignore_fields = get_ignore_fields(mp_entry);
-
-    let selected_fields = extract_selected_fields(&st_entry, mp_entry, &map_fields, &ignore_fields);
-
-    let tk_stream_iterator = selected_fields.iter().map(|field| {
-        let mut name = format!("{}", field.field_name.to_string());
-        let mut name_ident = format_ident!("{}", name.as_str());
+pub fn build_fields(st_entry: &StructEntry, mp_entry: &MapperEntry) -> Vec<TokenStream> {
+    let vis = get_field_vis(mp_entry);
 
-        let ty = &field.field_type;
+    let tk_stream_iterator = get_dto_fields(st_entry, mp_entry)
+        .into_iter()
+        .map(|dto_field| {
+            let name_ident = dto_field.ident();
+            let ty = dto_field.dto_type();
 
-        let mut attributes: Vec<TokenStream> = Vec::new();
+            //doc comments of the struct field are kept on the dto field
+            let docs = dto_field.source.doc_attributes();
+            let forwarded = get_forwarded_attributes(&dto_field, mp_entry);
+            let cfg_attrs = dto_field.cfg_attributes();
+            let cfg_attr_attrs = dto_field.source.cfg_attr_attributes();
 
-        if let Some(m_value) = map_fields.get(&name) {
-            //let's rename the struct field if there is a mapping for it
-            if let Some(ref new_name) = m_value.to_field {
-                name = new_name.clone();
-                name_ident = format_ident!("{}", name.as_str())
-            }
-
-            attributes = m_value
-                .macro_attr
+            let attributes: Vec<TokenStream> = dto_field
+                .map_value
                 .iter()
+                .flat_map(|m_value| m_value.macro_attr.iter())
                 .map(|attr| parse_str(attr).unwrap())
                 .collect();
 
-            if !m_value.required && !field.is_optional {
-                return quote! {
-                    #(#attributes)*
-                    pub #name_ident: Option<#ty>
-                };
+            quote! {
+                #(#cfg_attrs)*
+                #(#cfg_attr_attrs)*
+                #(#docs)*
+                #(#forwarded)*
+                #(#attributes)*
+                #vis #name_ident: #ty
             }
-        }
-
-        quote! {
-            #(#attributes)*
-            pub #name_ident: #ty
-        }
-    });
+        });
 
     let mut struct_fields = tk_stream_iterator.collect::<Vec<TokenStream>>();
     let new_field_token = build_new_fields_token(&mp_entry);
@@ -243,14 +665,44 @@ fn build_fields(st_entry: &StructEntry, mp_entry: &MapperEntry) -> Vec<TokenStre
     struct_fields
 }
 
+//attributes of the struct field whose path is listed in forward_attrs are copied onto the dto field
+fn get_forwarded_attributes<'a>(
+    dto_field: &'a DtoField,
+    mp_entry: &MapperEntry,
+) -> Vec<&'a Attribute> {
+    let forward_attrs = match dto_field.map_value {
+        Some(MapValue {
+            forward_attrs: Some(ref attrs),
+            ..
+        }) => attrs,
+        _ if mp_entry
+            .forward_exclude
+            .contains(&dto_field.source.field_name) =>
+        {
+            return Vec::new()
+        }
+        _ => &mp_entry.forward_attrs,
+    };
+
+    dto_field
+        .source
+        .attributes
+        .iter()
+        .filter(|attr| {
+            let path = attr.path();
+            forward_attrs.contains(&remove_white_space(&quote!(#path).to_string()))
+        })
+        .collect()
+}
+
 fn build_new_fields_token(mp_entry: &MapperEntry) -> Vec<TokenStream> {
+    let vis = get_field_vis(mp_entry);
     mp_entry
         .new_fields
         .iter()
         .map(|new_field| {
             let new_field_ident = format_ident!("{}", new_field.field_name.as_str());
-            let field_type: syn::Type = parse_str(&new_field.field_type)
-                .unwrap_or_else(|_| panic!("Failed to parse type: {}", new_field.field_type));
+            let field_type = parse_new_field_type(new_field);
 
             let attributes: Vec<TokenStream> = new_field
                 .attributes
@@ -260,8 +712,394 @@ fn build_new_fields_token(mp_entry: &MapperEntry) -> Vec<TokenStream> {
 
             quote! {
                 #(#attributes)*
-                pub #new_field_ident: #field_type
+                #vis #new_field_ident: #field_type
+            }
+        })
+        .collect()
+}
+
+fn parse_new_field_type(new_field: &NewField) -> syn::Type {
+    parse_str(&new_field.field_type)
+        .unwrap_or_else(|_| panic!("Failed to parse type: {}", new_field.field_type))
+}
+
+fn build_cfg_attribute(predicate: &str) -> TokenStream {
+    let predicate: TokenStream = parse_str(predicate).unwrap();
+    quote! { #[cfg(#predicate)] }
+}
+
+//everything generated for a mapper entry with a cfg predicate only exists under that predicate
+pub fn get_entry_cfg(mp_entry: &MapperEntry) -> TokenStream {
+    match mp_entry.cfg {
+        Some(ref predicate) => build_cfg_attribute(predicate),
+        None => quote! {},
+    }
+}
+
+//dto fields are public unless the mapper entry sets another visibility with field_vis
+fn get_field_vis(mp_entry: &MapperEntry) -> TokenStream {
+    match mp_entry.field_vis {
+        Some(ref vis) if vis.eq(PRIVATE_VIS) => quote! {},
+        Some(ref vis) => {
+            let vis: syn::Visibility = parse_str(vis).unwrap();
+            quote! { #vis }
+        }
+        None => quote! { pub },
+    }
+}
+
+//this is to build the getters and setters of the dto fields for entries that request them
+pub fn generate_accessors(
+    mapper_entries: &[MapperEntry],
+    struct_entry: &StructEntry,
+) -> Vec<TokenStream> {
+    mapper_entries
+        .iter()
+        .filter(|mp_entry| mp_entry.getters || mp_entry.setters)
+        .map(|mp_entry| {
+            let dto = format_ident!("{}", mp_entry.dto.as_str());
+
+            let cfg = get_entry_cfg(mp_entry);
+
+            //(field name, field type, is a Copy type, cfg attributes)
+            let mut fields: Vec<(syn::Ident, TokenStream, bool, Vec<TokenStream>)> =
+                get_dto_fields(struct_entry, mp_entry)
+                    .iter()
+                    .map(|dto_field| {
+                        let is_copy = dto_field.is_copy();
+                        let cfg_attrs = dto_field.cfg_attributes();
+                        (dto_field.ident(), dto_field.dto_type(), is_copy, cfg_attrs)
+                    })
+                    .collect();
+
+            mp_entry.new_fields.iter().for_each(|new_field| {
+                let field_type = parse_new_field_type(new_field);
+                let is_copy = is_type_copy(&field_type);
+                let name = format_ident!("{}", new_field.field_name.as_str());
+                fields.push((name, quote! { #field_type }, is_copy, Vec::new()));
+            });
+
+            let methods = fields.iter().map(|(name, ty, is_copy, cfg_attrs)| {
+                let mut methods: Vec<TokenStream> = Vec::new();
+                if mp_entry.getters && *is_copy {
+                    methods.push(quote! {
+                        #(#cfg_attrs)*
+                        pub fn #name(&self) -> #ty {
+                            self.#name
+                        }
+                    });
+                } else if mp_entry.getters {
+                    methods.push(quote! {
+                        #(#cfg_attrs)*
+                        pub fn #name(&self) -> &#ty {
+                            &self.#name
+                        }
+                    });
+                }
+
+                if mp_entry.setters {
+                    let setter = format_ident!("set_{}", name);
+                    let with = format_ident!("with_{}", name);
+                    methods.push(quote! {
+                        #(#cfg_attrs)*
+                        pub fn #setter(&mut self, value: impl Into<#ty>) {
+                            self.#name = value.into();
+                        }
+
+                        #(#cfg_attrs)*
+                        pub fn #with(mut self, value: impl Into<#ty>) -> Self {
+                            self.#name = value.into();
+                            self
+                        }
+                    });
+                }
+                quote! { #(#methods)* }
+            });
+
+            quote! {
+                #cfg
+                impl #dto {
+                    #(#methods)*
+                }
+            }
+        })
+        .collect()
+}
+
+//a dto with redacted fields gets a manual Debug implementation printing <redacted> for them
+pub fn generate_debug_impls(
+    mapper_entries: &[MapperEntry],
+    struct_entry: &StructEntry,
+) -> Vec<TokenStream> {
+    mapper_entries
+        .iter()
+        .filter_map(|mp_entry| {
+            let redacted_fields = mp_entry.get_redacted_fields();
+            if redacted_fields.is_empty() {
+                return None;
             }
+            let dto = format_ident!("{}", mp_entry.dto.as_str());
+            let dto_name = mp_entry.dto.as_str();
+            let cfg = get_entry_cfg(mp_entry);
+
+            //(field name, cfg attributes)
+            let mut fields: Vec<(syn::Ident, Vec<TokenStream>)> =
+                get_dto_fields(struct_entry, mp_entry)
+                    .iter()
+                    .map(|dto_field| (dto_field.ident(), dto_field.cfg_attributes()))
+                    .collect();
+            mp_entry.new_fields.iter().for_each(|new_field| {
+                fields.push((
+                    format_ident!("{}", new_field.field_name.as_str()),
+                    Vec::new(),
+                ));
+            });
+
+            let debug_fields = fields.iter().map(|(name, cfg_attrs)| {
+                let field_name = name.to_string();
+                if redacted_fields.contains(&field_name) {
+                    quote! {
+                        #(#cfg_attrs)*
+                        debug.field(#field_name, &format_args!("<redacted>"));
+                    }
+                } else {
+                    quote! {
+                        #(#cfg_attrs)*
+                        debug.field(#field_name, &self.#name);
+                    }
+                }
+            });
+
+            Some(quote! {
+                #cfg
+                impl ::std::fmt::Debug for #dto {
+                    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                        let mut debug = f.debug_struct(#dto_name);
+                        #(#debug_fields)*
+                        debug.finish()
+                    }
+                }
+            })
+        })
+        .collect()
+}
+
+//a dto with zeroized fields wipes them from memory when it is dropped
+pub fn generate_drop_impls(
+    mapper_entries: &[MapperEntry],
+    struct_entry: &StructEntry,
+) -> Vec<TokenStream> {
+    mapper_entries
+        .iter()
+        .filter(|mp_entry| !mp_entry.zeroize.is_empty())
+        .map(|mp_entry| {
+            let dto = format_ident!("{}", mp_entry.dto.as_str());
+            let cfg = get_entry_cfg(mp_entry);
+
+            let mut zeroized_fields: Vec<TokenStream> = get_dto_fields(struct_entry, mp_entry)
+                .iter()
+                .filter(|dto_field| mp_entry.zeroize.contains(&dto_field.name))
+                .map(|dto_field| {
+                    let name = dto_field.ident();
+                    let cfg_attrs = dto_field.cfg_attributes();
+                    quote! {
+                        #(#cfg_attrs)*
+                        ::zeroize::Zeroize::zeroize(&mut self.#name);
+                    }
+                })
+                .collect();
+            mp_entry
+                .new_fields
+                .iter()
+                .filter(|new_field| mp_entry.zeroize.contains(&new_field.field_name))
+                .for_each(|new_field| {
+                    let name = format_ident!("{}", new_field.field_name.as_str());
+                    zeroized_fields.push(quote! { ::zeroize::Zeroize::zeroize(&mut self.#name); });
+                });
+
+            quote! {
+                #cfg
+                impl Drop for #dto {
+                    fn drop(&mut self) {
+                        #(#zeroized_fields)*
+                    }
+                }
+            }
+        })
+        .collect()
+}
+
+//a dto converts directly into the sibling dtos listed in convert_to, whose fields it must all have.
+//The optional wrapping of each field is adapted to the target dto
+pub fn generate_convert_impls(
+    mapper_entries: &[MapperEntry],
+    struct_entry: &StructEntry,
+) -> Vec<TokenStream> {
+    mapper_entries
+        .iter()
+        .flat_map(|mp_entry| {
+            mp_entry
+                .convert_to
+                .iter()
+                .filter_map(|target| mapper_entries.iter().find(|t| &t.dto == target))
+                .map(|target_entry| build_convert_impl(mp_entry, target_entry, struct_entry))
+                .collect::<Vec<TokenStream>>()
+        })
+        .collect()
+}
+
+fn build_convert_impl(
+    mp_entry: &MapperEntry,
+    target_entry: &MapperEntry,
+    struct_entry: &StructEntry,
+) -> TokenStream {
+    let source_fields = get_dto_fields(struct_entry, mp_entry);
+    let mut init_fields: Vec<TokenStream> = get_dto_fields(struct_entry, target_entry)
+        .iter()
+        .filter_map(|target_field| {
+            let source_field = target_field.find_counterpart(&source_fields)?;
+            let target_name = target_field.ident();
+            let value = get_dto_value(quote! { value }, &source_field.ident(), mp_entry);
+            let value = match target_field.is_reversible() {
+                true => convert_dto_value(value, source_field, target_field),
+                false => value,
+            };
+            let mut cfg_attrs = target_field.cfg_attributes();
+            cfg_attrs.extend(source_field.cfg_attributes());
+            Some(quote! { #(#cfg_attrs)* #target_name: #value })
+        })
+        .collect();
+    target_entry.new_fields.iter().for_each(|new_field| {
+        let name = format_ident!("{}", new_field.field_name.as_str());
+        let value = get_dto_value(quote! { value }, &name, mp_entry);
+        init_fields.push(quote! { #name: #value });
+    });
+
+    let value = match (init_fields.is_empty(), mp_entry.zeroize.is_empty()) {
+        (true, _) => quote! { _value },
+        (false, true) => quote! { value },
+        (false, false) => quote! { mut value },
+    };
+    let dto = get_dto_path(mp_entry);
+    let target_dto = get_dto_path(target_entry);
+    let cfg = get_entry_cfg(mp_entry);
+    let target_cfg = get_entry_cfg(target_entry);
+    quote! {
+        #cfg
+        #target_cfg
+        impl From<#dto> for #target_dto {
+            fn from(#value: #dto) -> Self {
+                #target_dto {
+                    #(#init_fields),*
+                }
+            }
+        }
+    }
+}
+
+//a struct field is wrapped into Option, unwrapped from Option or kept as is by each dto
+fn convert_dto_value(value: TokenStream, source: &DtoField, target: &DtoField) -> TokenStream {
+    let source_shape = (source.is_wrapped(), source.is_unwrapped());
+    let target_shape = (target.is_wrapped(), target.is_unwrapped());
+    match (source_shape, target_shape) {
+        //a plain value into a wrapped one, or an unwrapped value into the Option of the struct field
+        ((false, false), (true, false)) | ((false, true), (false, false)) => {
+            quote! { Some(#value) }
+        }
+        //the Option of the struct field into an unwrapped value with a fallback, validate_conversions
+        //rejects the unwrapping of an Option without fallback
+        _ if unwraps_option(source, target) => match target.unwrap_or_expr() {
+            Some(fallback) => quote! { #value.unwrap_or_else(|| #fallback) },
+            None => value,
+        },
+        _ => value,
+    }
+}
+
+//a wrapped value into a plain one, or the Option of the struct field into an unwrapped value
+pub fn unwraps_option(source: &DtoField, target: &DtoField) -> bool {
+    let source_shape = (source.is_wrapped(), source.is_unwrapped());
+    let target_shape = (target.is_wrapped(), target.is_unwrapped());
+    matches!(
+        (source_shape, target_shape),
+        ((true, false), (false, false)) | ((false, false), (false, true))
+    )
+}
+
+//a dto extending another one converts into it. The fields it shares with the same type are moved
+//and the other fields of the extended dto get their default value
+pub fn generate_extends_impls(
+    mapper_entries: &[MapperEntry],
+    struct_entry: &StructEntry,
+) -> Vec<TokenStream> {
+    mapper_entries
+        .iter()
+        .filter_map(|mp_entry| {
+            let parent_entry = mapper_entries
+                .iter()
+                .find(|parent| Some(&parent.dto) == mp_entry.extends.as_ref())?;
+            let child_fields = get_dto_fields(struct_entry, mp_entry);
+            let parent_fields = get_dto_fields(struct_entry, parent_entry);
+
+            let mut init_fields: Vec<TokenStream> = Vec::new();
+            parent_fields.iter().for_each(|parent_field| {
+                let child_field =
+                    parent_field
+                        .find_counterpart(&child_fields)
+                        .filter(|child_field| {
+                            child_field.dto_type().to_string()
+                                == parent_field.dto_type().to_string()
+                        });
+                if let Some(child_field) = child_field {
+                    let parent_name = parent_field.ident();
+                    let child_value =
+                        get_dto_value(quote! { value }, &child_field.ident(), mp_entry);
+                    let mut cfg_attrs = parent_field.cfg_attributes();
+                    cfg_attrs.extend(child_field.cfg_attributes());
+                    init_fields.push(quote! { #(#cfg_attrs)* #parent_name: #child_value });
+                }
+            });
+            parent_entry.new_fields.iter().for_each(|parent_field| {
+                let is_shared = mp_entry.new_fields.iter().any(|child_field| {
+                    child_field.field_name == parent_field.field_name
+                        && remove_white_space(&child_field.field_type)
+                            == remove_white_space(&parent_field.field_type)
+                });
+                if is_shared {
+                    let name = format_ident!("{}", parent_field.field_name.as_str());
+                    let child_value = get_dto_value(quote! { value }, &name, mp_entry);
+                    init_fields.push(quote! { #name: #child_value });
+                }
+            });
+
+            let default_fields =
+                if init_fields.len() < parent_fields.len() + parent_entry.new_fields.len() {
+                    quote! { ..::core::default::Default::default() }
+                } else {
+                    quote! {}
+                };
+            let value = match (init_fields.is_empty(), mp_entry.zeroize.is_empty()) {
+                (true, _) => quote! { _value },
+                (false, true) => quote! { value },
+                (false, false) => quote! { mut value },
+            };
+            let dto = get_dto_path(mp_entry);
+            let parent_dto = get_dto_path(parent_entry);
+            let cfg = get_entry_cfg(mp_entry);
+            let parent_cfg = get_entry_cfg(parent_entry);
+
+            Some(quote! {
+                #cfg
+                #parent_cfg
+                impl From<#dto> for #parent_dto {
+                    fn from(#value: #dto) -> Self {
+                        #parent_dto {
+                            #(#init_fields,)*
+                            #default_fields
+                        }
+                    }
+                }
+            })
         })
         .collect()
 }
@@ -273,16 +1111,7 @@ fn build_init_new_fields_token(mp_entry: &MapperEntry) -> Vec<TokenStream> {
         .map(|new_field| {
             let name = format_ident!("{}", new_field.field_name.as_str());
             //let expr = &new_field.expression_value;
-            let expr: syn::Expr = match parse_str(new_field.expression_value.as_str()) {
-                Ok(assign_expr) => assign_expr,
-                Err(expr_error) => {
-                    //eprintln!("Failed to parse expression value {} : {}", new_field.expression_value, expr_error);
-                    panic!(
-                        r#"Failed to parse new field '{}' expression value "{}" : {}"#,
-                        new_field.field_name, new_field.expression_value, expr_error
-                    );
-                }
-            };
+            let expr = parse_new_field_expr(new_field);
             // let f_type = &new_field.field_type;
 
             // eprintln!("required = {:#?}", new_field.required);
@@ -294,27 +1123,73 @@ fn build_init_new_fields_token(mp_entry: &MapperEntry) -> Vec<TokenStream> {
         .collect()
 }
 
-fn get_ignore_fields(mp_entry: &MapperEntry) -> HashSet<String> {
-    let ignore_fields: HashSet<String> = mp_entry
-        .ignore
+pub fn parse_new_field_expr(new_field: &NewField) -> syn::Expr {
+    match parse_str(new_field.expression_value.as_str()) {
+        Ok(assign_expr) => assign_expr,
+        Err(expr_error) => {
+            //eprintln!("Failed to parse expression value {} : {}", new_field.expression_value, expr_error);
+            panic!(
+                r#"Failed to parse new field '{}' expression value "{}" : {}"#,
+                new_field.field_name, new_field.expression_value, expr_error
+            );
+        }
+    }
+}
+
+//ignore holds field names or glob patterns such as "internal_*", ignore_types and exclude_groups exclude fields
+//by type and by group
+pub fn get_ignore_fields(st_entry: &StructEntry, mp_entry: &MapperEntry) -> HashSet<String> {
+    st_entry
+        .field_entries
         .iter()
-        .map(|elem| elem.to_string())
-        .collect();
-    ignore_fields
+        .filter(|field| {
+            mp_entry
+                .ignore
+                .iter()
+                .any(|pattern| glob_match(pattern, &field.field_name))
+                || mp_entry
+                    .ignore_types
+                    .iter()
+                    .any(|pattern| field.matches_type(pattern))
+                || field
+                    .groups
+                    .iter()
+                    .any(|group| mp_entry.exclude_groups.contains(group))
+        })
+        .map(|field| field.field_name.to_string())
+        .collect()
+}
+
+fn has_exclusions(mp_entry: &MapperEntry) -> bool {
+    !mp_entry.ignore.is_empty()
+        || !mp_entry.ignore_types.is_empty()
+        || !mp_entry.exclude_groups.is_empty()
 }
 
+//with include_all or exclusions every field which isn't excluded is taken and map tuples only override them.
+//Otherwise only the mapped fields and the fields selected by include_types or include_groups are taken
 fn get_selected_fields(
     st_entry: &StructEntry,
+    mp_entry: &MapperEntry,
     ignore_fields: &HashSet<String>,
     map_fields: &HashMap<String, MapValue>,
 ) -> Vec<FieldEntry> {
-    let is_ignore = ignore_fields.len() > 0;
+    let is_ignore = mp_entry.include_all || has_exclusions(mp_entry);
     st_entry
         .field_entries
         .iter()
+        .filter(|&field| !ignore_fields.contains(&field.field_name))
         .filter(|&field| {
-            (is_ignore && !ignore_fields.contains(&field.field_name.to_string()))
-                || (!is_ignore && map_fields.contains_key(&field.field_name.to_string()))
+            is_ignore
+                || map_fields.contains_key(&field.field_name.to_string())
+                || mp_entry
+                    .include_types
+                    .iter()
+                    .any(|pattern| field.matches_type(pattern))
+                || field
+                    .groups
+                    .iter()
+                    .any(|group| mp_entry.include_groups.contains(group))
         })
         .map(|f| f.clone())
         .collect()
@@ -324,10 +1199,51 @@ fn get_all_fields(st_entry: &StructEntry) -> Vec<FieldEntry> {
     st_entry.field_entries.iter().map(|f| f.clone()).collect()
 }
 
-fn get_map_of_mapvalue(mp_entry: &MapperEntry) -> HashMap<String, MapValue> {
+//a map tuple whose source is a glob pattern applies to each matching struct field,
+//a map tuple naming the field takes precedence over it. A field named by several tuples keeps its primary one
+pub fn get_map_of_mapvalue(
+    st_entry: &StructEntry,
+    mp_entry: &MapperEntry,
+) -> HashMap<String, MapValue> {
     let mut map_fields: HashMap<String, MapValue> = HashMap::new();
-    mp_entry.map.iter().for_each(|mp_val| {
-        map_fields.insert(mp_val.from_field.to_string(), mp_val.clone());
-    });
+    mp_entry
+        .map
+        .iter()
+        .filter(|mp_val| is_glob(&mp_val.from_field))
+        .for_each(|mp_val| {
+            st_entry
+                .field_entries
+                .iter()
+                .filter(|field| glob_match(&mp_val.from_field, &field.field_name))
+                .for_each(|field| {
+                    let field_value = MapValue {
+                        from_field: field.field_name.to_string(),
+                        ..mp_val.clone()
+                    };
+                    map_fields.insert(field.field_name.to_string(), field_value);
+                });
+        });
+    let mut named_fields: HashSet<&str> = HashSet::new();
+    mp_entry
+        .map
+        .iter()
+        .filter(|mp_val| !is_glob(&mp_val.from_field))
+        .for_each(|mp_val| {
+            if named_fields.insert(mp_val.from_field.as_str()) || mp_val.primary {
+                map_fields.insert(mp_val.from_field.to_string(), mp_val.clone());
+            }
+        });
     map_fields
 }
+
+//the other tuples of a field named several times in map, each one results into its own dto field
+fn get_fanout_values<'a>(
+    mp_entry: &'a MapperEntry,
+    primary: &'a MapValue,
+) -> impl Iterator<Item = &'a MapValue> {
+    mp_entry.map.iter().filter(move |mp_val| {
+        !is_glob(&mp_val.from_field)
+            && mp_val.from_field == primary.from_field
+            && mp_val.dto_name() != primary.dto_name()
+    })
+}
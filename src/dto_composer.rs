@@ -0,0 +1,522 @@
+use std::collections::{HashMap, HashSet};
+
+use proc_macro2::{Span, TokenStream};
+use proc_macro_crate::{crate_name, FoundCrate};
+use quote::{format_ident, quote};
+use syn::{
+    braced, bracketed,
+    parse::{Parse, ParseStream},
+    parse_str, Attribute, Data, DeriveInput, LitStr, Path,
+};
+
+use crate::{
+    dto_builder::{
+        build_dto_struct, build_field_docs, build_fields, get_clones_first, get_dto_fields,
+        DtoField,
+    },
+    entry_validator::{validate_fanout, validate_sensitive_fields},
+    mapper_entry::{MapValue, MapperEntry},
+    struct_entry::StructEntry,
+    utils::{did_you_mean, glob_match, is_glob},
+};
+
+//each struct marked with #[mapper(composable)] gets a hidden macro named after it which hands its fields
+//over to compose!
+const SOURCE_MACRO_PREFIX: &str = "__dto_mapper_source_";
+const MAPPER: &str = "mapper";
+const COMPOSABLE: &str = "composable";
+
+//#[mapper(composable)] lets the struct be a source of compose!
+pub fn is_composable(attr: &Attribute) -> bool {
+    attr.path().is_ident(MAPPER)
+        && attr
+            .parse_args::<Path>()
+            .is_ok_and(|path| path.is_ident(COMPOSABLE))
+}
+
+//the macro_rules macro calls back the given macro with the payload followed by the struct definition
+pub fn generate_source_macro(input: &DeriveInput) -> TokenStream {
+    let name = &input.ident;
+    let fields = match input.data {
+        Data::Struct(ref data) => &data.fields,
+        _ => return quote! {},
+    };
+    let macro_name = format_ident!("{}{}", SOURCE_MACRO_PREFIX, name);
+    quote! {
+        #[doc(hidden)]
+        #[allow(unused_macros)]
+        macro_rules! #macro_name {
+            ([$($callback:tt)*] $($payload:tt)*) => {
+                $($callback)*! { $($payload)* struct #name #fields }
+            };
+        }
+
+        #[doc(hidden)]
+        #[allow(unused_imports)]
+        pub(crate) use #macro_name;
+    }
+}
+
+//input of compose! once the source structs have been collected:
+//["Account"] { #[mapper(...)] } struct User { ... }
+//where the bracket lists the sources left to collect
+pub struct ComposeInput {
+    pending: Vec<LitStr>,
+    attrs: Vec<Attribute>,
+    structs: Vec<DeriveInput>,
+}
+
+impl Parse for ComposeInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        bracketed!(content in input);
+        let mut pending = Vec::new();
+        while !content.is_empty() {
+            pending.push(content.parse::<LitStr>()?);
+        }
+
+        let content;
+        braced!(content in input);
+        let attrs = content.call(Attribute::parse_outer)?;
+
+        let mut structs = Vec::new();
+        while !input.is_empty() {
+            structs.push(input.parse::<DeriveInput>()?);
+        }
+        Ok(Self {
+            pending,
+            attrs,
+            structs,
+        })
+    }
+}
+
+impl ComposeInput {
+    //compose! is written with #[mapper(...)] attributes only, their sources are collected one by one
+    pub fn parse_declaration(input: ParseStream) -> syn::Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let mut pending: Vec<LitStr> = Vec::new();
+        for attr in attrs.iter() {
+            let mp_entry = MapperEntry::build_composed(attr)?;
+            for source in mp_entry.sources.iter() {
+                //a source is collected once even when written with different paths
+                if !pending
+                    .iter()
+                    .any(|lit| source_name(&lit.value()) == source_name(source))
+                {
+                    pending.push(LitStr::new(source, attr_span(&mp_entry)));
+                }
+            }
+        }
+        if !input.is_empty() {
+            return Err(input.error("compose! only accepts #[mapper(...)] attributes"));
+        }
+        Ok(Self {
+            pending,
+            attrs,
+            structs: Vec::new(),
+        })
+    }
+
+    pub fn expand(mut self) -> syn::Result<TokenStream> {
+        if self.pending.is_empty() {
+            let mut expanded = TokenStream::new();
+            for attr in self.attrs.iter() {
+                let mp_entry = MapperEntry::build_composed(attr)?;
+                expanded.extend(compose_dto(&mp_entry, &self.structs)?);
+            }
+            return Ok(expanded);
+        }
+
+        //the hidden macro of the next source calls back compose with its struct definition
+        let source = self.pending.remove(0);
+        let mut macro_path: Path = source.parse()?;
+        if let Some(last) = macro_path.segments.last_mut() {
+            last.ident = format_ident!(
+                "{}{}",
+                SOURCE_MACRO_PREFIX,
+                last.ident,
+                span = source.span()
+            );
+        }
+        let pending = &self.pending;
+        let attrs = &self.attrs;
+        let structs = &self.structs;
+        let crate_path = get_crate_path();
+        Ok(quote! {
+            #macro_path! {
+                [#crate_path::__compose_sources] [#(#pending)*] { #(#attrs)* } #(#structs)*
+            }
+        })
+    }
+}
+
+//dto_mapper may be renamed in the Cargo.toml of the crate calling compose!
+fn get_crate_path() -> TokenStream {
+    match crate_name("dto_mapper") {
+        Ok(FoundCrate::Name(name)) => {
+            let name = format_ident!("{}", name);
+            quote! { ::#name }
+        }
+        _ => quote! { ::dto_mapper },
+    }
+}
+
+fn attr_span(mp_entry: &MapperEntry) -> Span {
+    mp_entry.span.unwrap_or_else(Span::call_site)
+}
+
+//the name of a source is the last segment of its path such as User for "models::User"
+fn source_name(source: &str) -> String {
+    source
+        .rsplit("::")
+        .next()
+        .unwrap_or(source)
+        .trim()
+        .to_string()
+}
+
+struct ComposedSource {
+    path: Path,
+    st_entry: StructEntry,
+    dto_fields: Vec<DtoField>,
+    fields: Vec<TokenStream>,
+}
+
+fn compose_dto(mp_entry: &MapperEntry, structs: &[DeriveInput]) -> syn::Result<TokenStream> {
+    let span = attr_span(mp_entry);
+    if mp_entry.sources.len() < 2 {
+        return Err(syn::Error::new(
+            span,
+            format!(
+                "dto={} needs at least two `sources`, a dto of a single struct is declared with #[mapper] on the struct",
+                mp_entry.dto
+            ),
+        ));
+    }
+    let names: Vec<String> = mp_entry.sources.iter().map(|s| source_name(s)).collect();
+    validate_source_prefixes(mp_entry, &names)?;
+    validate_fanouts(mp_entry)?;
+
+    let mut sources: Vec<ComposedSource> = Vec::new();
+    for (source, name) in mp_entry.sources.iter().zip(names.iter()) {
+        let input = structs
+            .iter()
+            .find(|input| &input.ident.to_string() == name)
+            .ok_or_else(|| syn::Error::new(span, format!("source `{}` wasn't found", source)))?;
+        let st_entry = StructEntry::build_struct_entry(Box::new(input.clone()))?;
+        let source_entry = get_source_entry(mp_entry, name);
+        validate_source_fields(mp_entry, &st_entry, &source_entry)?;
+        validate_sensitive_fields(&st_entry, &source_entry)
+            .map_err(|error| error.to_syn_error())?;
+        let dto_fields = get_dto_fields(&st_entry, &source_entry);
+        validate_unwrapped_fields(mp_entry, &st_entry, &dto_fields)?;
+        sources.push(ComposedSource {
+            path: parse_str(source)?,
+            dto_fields,
+            fields: build_fields(&st_entry, &source_entry),
+            st_entry,
+        });
+    }
+    validate_field_clashes(mp_entry, &sources)?;
+
+    let mut docs: Vec<String> = Vec::new();
+    if let Some(ref doc) = mp_entry.doc {
+        doc.lines()
+            .for_each(|line| docs.push(format!(" {}", line.trim())));
+        docs.push(String::new());
+    }
+    docs.push(format!(
+        " Data transfer object generated by dto_mapper from {}.",
+        names
+            .iter()
+            .map(|name| format!("`{}`", name))
+            .collect::<Vec<String>>()
+            .join(", ")
+    ));
+    docs.push(String::new());
+    sources.iter().for_each(|source| {
+        docs.extend(build_field_docs(&source.st_entry, &source.dto_fields));
+    });
+
+    let fields: Vec<TokenStream> = sources
+        .iter()
+        .flat_map(|source| source.fields.iter().cloned())
+        .collect();
+    let dto_struct = build_dto_struct(mp_entry, &fields, &docs);
+    let from_sources = build_from_sources(mp_entry, &sources);
+    let into_sources = build_into_sources(mp_entry, &sources);
+
+    Ok(quote! {
+        #dto_struct
+
+        #from_sources
+
+        #into_sources
+    })
+}
+
+//the map and ignore names of the source with their prefix removed
+fn get_source_entry(mp_entry: &MapperEntry, name: &str) -> MapperEntry {
+    let prefix = format!("{}.", name);
+    MapperEntry {
+        dto: mp_entry.dto.clone(),
+        map: mp_entry
+            .map
+            .iter()
+            .filter_map(|m_value| {
+                let from_field = m_value.from_field.strip_prefix(&prefix)?;
+                Some(MapValue {
+                    from_field: from_field.to_string(),
+                    ..m_value.clone()
+                })
+            })
+            .collect(),
+        ignore: mp_entry
+            .ignore
+            .iter()
+            .filter_map(|field| field.strip_prefix(&prefix).map(|f| f.to_string()))
+            .collect(),
+        include_all: mp_entry.include_all,
+        forward_attrs: mp_entry.forward_attrs.clone(),
+        forward_exclude: mp_entry
+            .forward_exclude
+            .iter()
+            .filter_map(|field| field.strip_prefix(&prefix).map(|f| f.to_string()))
+            .collect(),
+        field_vis: mp_entry.field_vis.clone(),
+        allow_sensitive: mp_entry
+            .allow_sensitive
+            .iter()
+            .filter_map(|field| field.strip_prefix(&prefix).map(|f| f.to_string()))
+            .collect(),
+        span: mp_entry.span,
+        ..MapperEntry::default()
+    }
+}
+
+//a source field mapped several times needs a primary tuple to be converted back
+fn validate_fanouts(mp_entry: &MapperEntry) -> syn::Result<()> {
+    let mut from_fields: HashSet<&str> = HashSet::new();
+    let fanned_out = mp_entry
+        .map
+        .iter()
+        .filter(|m_value| !from_fields.insert(m_value.from_field.as_str()));
+    for m_value in fanned_out {
+        if let Some(message) = validate_fanout(mp_entry, &m_value.from_field) {
+            return Err(syn::Error::new(attr_span(mp_entry), message));
+        }
+    }
+    Ok(())
+}
+
+fn validate_source_prefixes(mp_entry: &MapperEntry, names: &[String]) -> syn::Result<()> {
+    let selected = mp_entry
+        .map
+        .iter()
+        .map(|m_value| &m_value.from_field)
+        .chain(mp_entry.ignore.iter())
+        .chain(mp_entry.forward_exclude.iter())
+        .chain(mp_entry.allow_sensitive.iter());
+    for field in selected {
+        let prefix = field.split('.').next().unwrap_or_default();
+        if field.contains('.') && names.iter().any(|name| name == prefix) {
+            continue;
+        }
+        return Err(syn::Error::new(
+            attr_span(mp_entry),
+            format!(
+                "dto={} selects `{}` which must be prefixed by one of its sources such as `{}.{}`{}",
+                mp_entry.dto,
+                field,
+                names[0],
+                field.rsplit('.').next().unwrap_or_default(),
+                did_you_mean(prefix, names.iter().map(|name| name.as_str()))
+            ),
+        ));
+    }
+    Ok(())
+}
+
+fn validate_source_fields(
+    mp_entry: &MapperEntry,
+    st_entry: &StructEntry,
+    source_entry: &MapperEntry,
+) -> syn::Result<()> {
+    let field_names = || st_entry.field_entries.iter().map(|f| f.field_name.as_str());
+    let selected = source_entry
+        .map
+        .iter()
+        .map(|m_value| &m_value.from_field)
+        .chain(source_entry.ignore.iter());
+    for field in selected {
+        let found = if is_glob(field) {
+            field_names().any(|name| glob_match(field, name))
+        } else {
+            field_names().any(|name| name == field)
+        };
+        if !found {
+            return Err(syn::Error::new(
+                attr_span(mp_entry),
+                format!(
+                    "dto={} selects `{}.{}` not found in {}{}",
+                    mp_entry.dto,
+                    st_entry.name,
+                    field,
+                    st_entry.name,
+                    did_you_mean(field, field_names())
+                ),
+            ));
+        }
+    }
+    Ok(())
+}
+
+//the conversions are infallible both ways, an unwrapped field needs an unwrap_or fallback
+//and a wrapped field would have no value to give back to its source
+fn validate_unwrapped_fields(
+    mp_entry: &MapperEntry,
+    st_entry: &StructEntry,
+    dto_fields: &[DtoField],
+) -> syn::Result<()> {
+    if let Some(dto_field) = dto_fields.iter().find(|dto_field| dto_field.is_wrapped()) {
+        return Err(syn::Error::new(
+            attr_span(mp_entry),
+            format!(
+                "dto={} wraps `{}.{}` in an Option which can't be converted back into {}, map it with required=true",
+                mp_entry.dto, st_entry.name, dto_field.source.field_name, st_entry.name
+            ),
+        ));
+    }
+    match dto_fields.iter().find(|dto_field| dto_field.is_fallible()) {
+        Some(dto_field) => Err(syn::Error::new(
+            attr_span(mp_entry),
+            format!(
+                "dto={} unwraps `{}.{}` without unwrap_or, add a fallback such as unwrap_or=\"Default::default()\"",
+                mp_entry.dto, st_entry.name, dto_field.source.field_name
+            ),
+        )),
+        None => Ok(()),
+    }
+}
+
+//fields selected from several sources under the same name must be renamed
+fn validate_field_clashes(mp_entry: &MapperEntry, sources: &[ComposedSource]) -> syn::Result<()> {
+    let mut names: HashMap<&str, &str> = HashMap::new();
+    for source in sources.iter() {
+        for dto_field in source.dto_fields.iter() {
+            let source_name = source.st_entry.name.as_str();
+            if let Some(other) = names.insert(dto_field.name.as_str(), source_name) {
+                return Err(syn::Error::new(
+                    attr_span(mp_entry),
+                    format!(
+                        "dto={} selects the field `{}` from both {} and {}, rename one of them such as (\"{}.{}:{}_{}\", true)",
+                        mp_entry.dto,
+                        dto_field.name,
+                        other,
+                        source_name,
+                        source_name,
+                        dto_field.source.field_name,
+                        source_name.to_lowercase(),
+                        dto_field.source.field_name
+                    ),
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+//the dto is built from a tuple of its sources
+fn build_from_sources(mp_entry: &MapperEntry, sources: &[ComposedSource]) -> TokenStream {
+    let dto = format_ident!("{}", mp_entry.dto.as_str());
+    let paths: Vec<&Path> = sources.iter().map(|source| &source.path).collect();
+    let init_fields: Vec<TokenStream> = sources
+        .iter()
+        .enumerate()
+        .flat_map(|(index, source)| {
+            let index = syn::Index::from(index);
+            get_clones_first(source.dto_fields.clone())
+                .into_iter()
+                .map(move |dto_field| {
+                    let name = dto_field.ident();
+                    let cfg_attrs = dto_field.cfg_attributes();
+                    let value = dto_field.read_source(quote! { value.#index });
+                    //validate_unwrapped_fields rejects an unwrapped field without fallback
+                    let value = match dto_field.unwrap_or_expr() {
+                        Some(fallback) if dto_field.is_unwrapped() => {
+                            quote! { #value.unwrap_or_else(|| #fallback) }
+                        }
+                        _ => dto_field.convert_value(value),
+                    };
+                    quote! { #(#cfg_attrs)* #name: #value }
+                })
+        })
+        .collect();
+    let value = if init_fields.is_empty() {
+        quote! { _value }
+    } else {
+        quote! { value }
+    };
+
+    quote! {
+        impl From<(#(#paths),*)> for #dto {
+            fn from(#value: (#(#paths),*)) -> Self {
+                #dto {
+                    #(#init_fields),*
+                }
+            }
+        }
+    }
+}
+
+//the dto is split back into its sources, the fields it doesn't have get their default value
+fn build_into_sources(mp_entry: &MapperEntry, sources: &[ComposedSource]) -> TokenStream {
+    let dto = format_ident!("{}", mp_entry.dto.as_str());
+    let paths: Vec<&Path> = sources.iter().map(|source| &source.path).collect();
+    let structs: Vec<TokenStream> = sources
+        .iter()
+        .map(|source| {
+            let path = &source.path;
+            let init_fields: Vec<TokenStream> = source
+                .dto_fields
+                .iter()
+                .filter(|dto_field| dto_field.is_reversible())
+                .map(|dto_field| {
+                    let name = dto_field.ident();
+                    let source_name = dto_field.source_ident();
+                    let cfg_attrs = dto_field.cfg_attributes();
+                    if dto_field.is_unwrapped() {
+                        quote! { #(#cfg_attrs)* #source_name: Some(value.#name) }
+                    } else {
+                        quote! { #(#cfg_attrs)* #source_name: value.#name }
+                    }
+                })
+                .collect();
+            let default_fields = if init_fields.len() < source.st_entry.field_entries.len() {
+                quote! { ..::core::default::Default::default() }
+            } else {
+                quote! {}
+            };
+            quote! {
+                #path {
+                    #(#init_fields,)*
+                    #default_fields
+                }
+            }
+        })
+        .collect();
+    let value = if sources.iter().all(|source| source.dto_fields.is_empty()) {
+        quote! { _value }
+    } else {
+        quote! { value }
+    };
+
+    quote! {
+        impl From<#dto> for (#(#paths),*) {
+            fn from(#value: #dto) -> Self {
+                (#(#structs),*)
+            }
+        }
+    }
+}
@@ -0,0 +1,435 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{punctuated::Punctuated, spanned::Spanned, Attribute, Expr, ExprLit, Lit, Meta, Token};
+
+use crate::{
+    dto_builder::{
+        get_dto_fields, get_dto_path, get_dto_value, get_entry_cfg, parse_new_field_expr, DtoField,
+    },
+    mapper_entry::MapperEntry,
+    struct_entry::StructEntry,
+    utils::{did_you_mean, to_snake_case},
+};
+
+const SPLIT: &str = "split";
+const JOIN: &str = "join";
+const FILL: &str = "fill";
+
+#[derive(PartialEq)]
+pub enum PartsKind {
+    //into_parts moves the struct fields into the dtos
+    Split,
+    //from_parts builds the struct back from the dtos
+    Join,
+}
+
+//a mapper attribute such as #[mapper(split = ["LoginDto", "ProfileDto"])] or #[mapper(join = [...])]
+//lists dtos of the struct whose fields are disjoint instead of declaring a dto
+pub struct PartsEntry {
+    pub kind: PartsKind,
+    pub dtos: Vec<String>,
+    //struct fields which none of the joined dtos has, set to their default value
+    pub fill: Vec<String>,
+    pub span: Span,
+}
+
+impl PartsEntry {
+    //returns None when the attribute isn't a split or join declaration
+    pub fn build(attr: &Attribute) -> syn::Result<Option<Self>> {
+        let nested = attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+        let kind = match (
+            nested.iter().any(|meta| meta.path().is_ident(SPLIT)),
+            nested.iter().any(|meta| meta.path().is_ident(JOIN)),
+        ) {
+            (false, false) => return Ok(None),
+            (true, true) => {
+                return Err(syn::Error::new(
+                    attr.span(),
+                    "`split` and `join` must be declared in separate mapper attributes",
+                ))
+            }
+            (true, false) => PartsKind::Split,
+            (false, true) => PartsKind::Join,
+        };
+
+        let mut parts = Self {
+            kind,
+            dtos: Vec::new(),
+            fill: Vec::new(),
+            span: attr.span(),
+        };
+        for meta in nested.iter() {
+            match meta {
+                Meta::NameValue(metaname)
+                    if metaname.path.is_ident(SPLIT) || metaname.path.is_ident(JOIN) =>
+                {
+                    parts.dtos = parse_names(&metaname.value)?;
+                }
+                Meta::NameValue(metaname)
+                    if metaname.path.is_ident(FILL) && parts.kind == PartsKind::Join =>
+                {
+                    parts.fill = parse_names(&metaname.value)?;
+                }
+                _ if parts.kind == PartsKind::Split => {
+                    return Err(syn::Error::new(
+                        meta.span(),
+                        format!("a split declaration only accepts `{}`", SPLIT),
+                    ))
+                }
+                _ => {
+                    return Err(syn::Error::new(
+                        meta.span(),
+                        format!("a join declaration only accepts `{}` and `{}`", JOIN, FILL),
+                    ))
+                }
+            }
+        }
+        Ok(Some(parts))
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self.kind {
+            PartsKind::Split => SPLIT,
+            PartsKind::Join => JOIN,
+        }
+    }
+
+    //the dtos must exist and each struct field can only be moved into one of them
+    pub fn validate(
+        &self,
+        st_entry: &StructEntry,
+        mapper_entries: &[MapperEntry],
+    ) -> syn::Result<()> {
+        let name = self.name();
+        if self.dtos.len() < 2 {
+            return Err(syn::Error::new(
+                self.span,
+                format!(
+                    "`{}` needs at least two dtos, a single dto is converted with into()",
+                    name
+                ),
+            ));
+        }
+        let mut owners: Vec<(String, &str)> = Vec::new();
+        for dto in self.dtos.iter() {
+            let Some(mp_entry) = mapper_entries.iter().find(|mp_entry| &mp_entry.dto == dto) else {
+                return Err(syn::Error::new(
+                    self.span,
+                    format!(
+                        "`{}` lists `{}` which isn't a dto of {}{}",
+                        name,
+                        dto,
+                        st_entry.name,
+                        did_you_mean(dto, mapper_entries.iter().map(|mp| mp.dto.as_str()))
+                    ),
+                ));
+            };
+            for dto_field in get_dto_fields(st_entry, mp_entry) {
+                let field_name = dto_field.source.field_name.clone();
+                //split moves each field once and join only takes the fields converted back into the struct
+                if !dto_field.is_reversible() {
+                    if self.kind == PartsKind::Join {
+                        continue;
+                    }
+                    return Err(syn::Error::new(
+                        self.span,
+                        format!(
+                            "dto={} can't be split from {} because it clones or converts `{}`",
+                            dto, st_entry.name, field_name
+                        ),
+                    ));
+                }
+                if let Some((_, owner)) = owners.iter().find(|(name, _)| name == &field_name) {
+                    return Err(syn::Error::new(
+                        self.span,
+                        format!(
+                            "`{}` dtos must be disjoint but the field `{}` of {} is mapped by both {} and {}",
+                            name, field_name, st_entry.name, owner, dto
+                        ),
+                    ));
+                }
+                if self.kind == PartsKind::Join && dto_field.is_wrapped() {
+                    return Err(syn::Error::new(
+                        self.span,
+                        format!(
+                            "dto={} can't be joined into {} because it wraps `{}` in an Option, map it with required=true",
+                            dto, st_entry.name, field_name
+                        ),
+                    ));
+                }
+                if self.kind == PartsKind::Split && dto_field.is_fallible() {
+                    return Err(syn::Error::new(
+                        self.span,
+                        format!(
+                            "dto={} can't be split from {} because it unwraps `{}` without unwrap_or",
+                            dto, st_entry.name, field_name
+                        ),
+                    ));
+                }
+                owners.push((field_name, dto.as_str()));
+            }
+        }
+        if self.kind == PartsKind::Join {
+            self.validate_coverage(st_entry, &owners)?;
+        }
+        Ok(())
+    }
+
+    //every struct field is either moved from one of the joined dtos or filled with its default value
+    fn validate_coverage(
+        &self,
+        st_entry: &StructEntry,
+        owners: &[(String, &str)],
+    ) -> syn::Result<()> {
+        let field_names = || st_entry.field_entries.iter().map(|f| f.field_name.as_str());
+        for fill in self.fill.iter() {
+            if !field_names().any(|name| name == fill) {
+                return Err(syn::Error::new(
+                    self.span,
+                    format!(
+                        "`{}` fills `{}` not found in {}{}",
+                        JOIN,
+                        fill,
+                        st_entry.name,
+                        did_you_mean(fill, field_names())
+                    ),
+                ));
+            }
+            if let Some((_, owner)) = owners.iter().find(|(name, _)| name == fill) {
+                return Err(syn::Error::new(
+                    self.span,
+                    format!(
+                        "`{}` fills `{}` which is already mapped by {}",
+                        JOIN, fill, owner
+                    ),
+                ));
+            }
+        }
+        let missing_fields: Vec<&str> = field_names()
+            .filter(|name| {
+                !owners.iter().any(|(owner, _)| owner == name)
+                    && !self.fill.iter().any(|fill| fill == name)
+            })
+            .collect();
+        if !missing_fields.is_empty() {
+            return Err(syn::Error::new(
+                self.span,
+                format!(
+                    "`{}` dtos don't cover the fields {:?} of {}, map them or list them in `{}` to set their default value",
+                    JOIN, missing_fields, st_entry.name, FILL
+                ),
+            ));
+        }
+        Ok(())
+    }
+}
+
+fn parse_names(expr: &Expr) -> syn::Result<Vec<String>> {
+    let Expr::Array(ref expr_arr) = expr else {
+        return Err(syn::Error::new(
+            expr.span(),
+            "expected an array of names such as [\"LoginDto\", \"ProfileDto\"]",
+        ));
+    };
+    expr_arr
+        .elems
+        .iter()
+        .map(|elem| match elem {
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(lit_str),
+                ..
+            }) => Ok(lit_str.value().trim().to_string()),
+            _ => Err(syn::Error::new(
+                elem.span(),
+                "names must be string literals",
+            )),
+        })
+        .collect()
+}
+
+//into_parts moves each struct field into the dto it belongs to without cloning it.
+//The new fields are computed first since their expressions may borrow the struct
+pub fn generate_split_impls(
+    parts_entries: &[PartsEntry],
+    mapper_entries: &[MapperEntry],
+    struct_entry: &StructEntry,
+) -> Vec<TokenStream> {
+    parts_entries
+        .iter()
+        .filter(|parts| parts.kind == PartsKind::Split)
+        .map(|parts| {
+            let mp_entries = get_part_entries(parts, mapper_entries);
+
+            let new_values: Vec<TokenStream> = mp_entries
+                .iter()
+                .flat_map(|mp_entry| {
+                    mp_entry.new_fields.iter().map(move |new_field| {
+                        let local = get_new_field_local(mp_entry, &new_field.field_name);
+                        let expr = parse_new_field_expr(new_field);
+                        quote! { let #local = #expr; }
+                    })
+                })
+                .collect();
+
+            let dtos: Vec<TokenStream> = mp_entries
+                .iter()
+                .map(|mp_entry| {
+                    let dto = get_dto_path(mp_entry);
+                    let mut init_fields: Vec<TokenStream> = get_dto_fields(struct_entry, mp_entry)
+                        .iter()
+                        .map(build_split_field)
+                        .collect();
+                    mp_entry.new_fields.iter().for_each(|new_field| {
+                        let name = format_ident!("{}", new_field.field_name.as_str());
+                        let local = get_new_field_local(mp_entry, &new_field.field_name);
+                        init_fields.push(quote! { #name: #local });
+                    });
+                    quote! {
+                        #dto {
+                            #(#init_fields),*
+                        }
+                    }
+                })
+                .collect();
+
+            let dto_idents: Vec<syn::Path> = mp_entries
+                .iter()
+                .map(|mp_entry| get_dto_path(mp_entry))
+                .collect();
+            let cfgs: Vec<TokenStream> = mp_entries.iter().map(|mp| get_entry_cfg(mp)).collect();
+            let struct_name = format_ident!("{}", struct_entry.name.as_str());
+            let doc = format!(
+                " Moves the fields of `{}` into {} without cloning them.",
+                struct_entry.name,
+                get_dto_list(parts)
+            );
+            quote! {
+                #(#cfgs)*
+                impl #struct_name {
+                    #[doc = #doc]
+                    pub fn into_parts(self) -> (#(#dto_idents),*) {
+                        #(#new_values)*
+                        (#(#dtos),*)
+                    }
+                }
+            }
+        })
+        .collect()
+}
+
+fn get_new_field_local(mp_entry: &MapperEntry, field_name: &str) -> syn::Ident {
+    format_ident!("__{}_{}", mp_entry.dto_name().to_lowercase(), field_name)
+}
+
+fn build_split_field(dto_field: &DtoField) -> TokenStream {
+    let name = dto_field.ident();
+    let source_name = dto_field.source_ident();
+    let cfg_attrs = dto_field.cfg_attributes();
+    if dto_field.is_wrapped() {
+        return quote! { #(#cfg_attrs)* #name: Some(self.#source_name) };
+    }
+    match dto_field.unwrap_or_expr() {
+        Some(fallback) if dto_field.is_unwrapped() => {
+            quote! { #(#cfg_attrs)* #name: self.#source_name.unwrap_or_else(|| #fallback) }
+        }
+        _ => quote! { #(#cfg_attrs)* #name: self.#source_name },
+    }
+}
+
+fn get_part_entries<'a>(
+    parts: &PartsEntry,
+    mapper_entries: &'a [MapperEntry],
+) -> Vec<&'a MapperEntry> {
+    parts
+        .dtos
+        .iter()
+        .filter_map(|dto| mapper_entries.iter().find(|mp| &mp.dto == dto))
+        .collect()
+}
+
+fn get_dto_list(parts: &PartsEntry) -> String {
+    parts
+        .dtos
+        .iter()
+        .map(|dto| format!("`{}`", dto))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+//from_parts builds the struct with a single literal, each field moved from the dto mapping it
+//or filled with its default value
+pub fn generate_join_impls(
+    parts_entries: &[PartsEntry],
+    mapper_entries: &[MapperEntry],
+    struct_entry: &StructEntry,
+) -> Vec<TokenStream> {
+    parts_entries
+        .iter()
+        .filter(|parts| parts.kind == PartsKind::Join)
+        .map(|parts| {
+            let mp_entries = get_part_entries(parts, mapper_entries);
+            let params: Vec<syn::Ident> = mp_entries
+                .iter()
+                .map(|mp_entry| format_ident!("{}", to_snake_case(mp_entry.dto_name())))
+                .collect();
+
+            let mut init_fields: Vec<TokenStream> = Vec::new();
+            let mut param_decls: Vec<TokenStream> = Vec::new();
+            for (mp_entry, param) in mp_entries.iter().zip(params.iter()) {
+                let dto = get_dto_path(mp_entry);
+                let dto_fields: Vec<DtoField> = get_dto_fields(struct_entry, mp_entry)
+                    .into_iter()
+                    .filter(|dto_field| dto_field.is_reversible())
+                    .collect();
+                param_decls.push(match (dto_fields.is_empty(), mp_entry.zeroize.is_empty()) {
+                    (true, _) => {
+                        let param = format_ident!("_{}", param);
+                        quote! { #param: #dto }
+                    }
+                    (false, true) => quote! { #param: #dto },
+                    (false, false) => quote! { mut #param: #dto },
+                });
+                dto_fields.iter().for_each(|dto_field| {
+                    let source_name = dto_field.source_ident();
+                    let cfg_attrs = dto_field.cfg_attributes();
+                    let value = get_dto_value(quote! { #param }, &dto_field.ident(), mp_entry);
+                    init_fields.push(if dto_field.is_unwrapped() {
+                        quote! { #(#cfg_attrs)* #source_name: Some(#value) }
+                    } else {
+                        quote! { #(#cfg_attrs)* #source_name: #value }
+                    });
+                });
+            }
+            struct_entry
+                .field_entries
+                .iter()
+                .filter(|field| parts.fill.contains(&field.field_name))
+                .for_each(|field| {
+                    let source_name = format_ident!("{}", field.field_name.as_str());
+                    let cfg_attrs = field.cfg_attributes();
+                    init_fields.push(quote! {
+                        #(#cfg_attrs)* #source_name: ::core::default::Default::default()
+                    });
+                });
+
+            let cfgs: Vec<TokenStream> = mp_entries.iter().map(|mp| get_entry_cfg(mp)).collect();
+            let struct_name = format_ident!("{}", struct_entry.name.as_str());
+            let doc = format!(
+                " Builds `{}` back from {} without cloning their fields.",
+                struct_entry.name,
+                get_dto_list(parts)
+            );
+            quote! {
+                #(#cfgs)*
+                impl #struct_name {
+                    #[doc = #doc]
+                    pub fn from_parts(#(#param_decls),*) -> Self {
+                        #struct_name {
+                            #(#init_fields),*
+                        }
+                    }
+                }
+            }
+        })
+        .collect()
+}
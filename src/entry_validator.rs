@@ -3,13 +3,33 @@ use std::{
     ops::Add,
 };
 
-use crate::{mapper_entry::MapperEntry, struct_entry::StructEntry};
+use proc_macro2::Span;
+
+use crate::{
+    dto_builder::{get_dto_fields, get_ignore_fields, get_map_of_mapvalue, unwraps_option},
+    mapper_entry::{MapValue, MapperEntry},
+    struct_entry::StructEntry,
+    utils::{did_you_mean, glob_match, is_glob, remove_white_space},
+};
 
 #[derive(Debug)]
 #[allow(dead_code)]
 pub enum FieldError {
-    DupField(String),
-    MissingField(String),
+    DupField(Option<Span>, String),
+    MissingField(Option<Span>, String),
+    InvalidOption(Option<Span>, String),
+}
+
+impl FieldError {
+    fn to_syn_error(&self) -> syn::Error {
+        match self {
+            FieldError::DupField(span, message)
+            | FieldError::MissingField(span, message)
+            | FieldError::InvalidOption(span, message) => {
+                syn::Error::new(span.unwrap_or_else(Span::call_site), message)
+            }
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -17,8 +37,63 @@ pub enum FieldError {
 pub enum ValidationError {
     MapperEntryError(Vec<FieldError>),
     StructEntryError(Vec<FieldError>),
-    DtoNameDuplicated(Vec<String>),
-    MissingPropertyError(String),
+    //errors below are reported on the #[mapper(...)] attribute they come from
+    DtoNameDuplicated(Option<Span>, String),
+    MissingPropertyError(Option<Span>, String),
+    IgnoredFieldNotFound(Option<Span>, String),
+    MapIgnoreConflict(Option<Span>, String),
+    NewFieldCollision(Option<Span>, String),
+    NewFieldDuplicated(Option<Span>, String),
+    DtoNameIsStructName(Option<Span>, String),
+    InvalidDtoName(Option<Span>, String),
+    InvalidNewField(Option<Span>, String),
+    ExactlyWithMap(Option<Span>, String),
+    PatternMatchesNothing(Option<Span>, String),
+    PatternRenamed(Option<Span>, String),
+    UnknownGroup(Option<Span>, String),
+    InvalidConversion(Option<Span>, String),
+    UnaccountedField(Option<Span>, String),
+    SensitiveField(Option<Span>, String),
+    RedactedField(Option<Span>, String),
+    ZeroizedField(Option<Span>, String),
+}
+
+impl ValidationError {
+    //errors are turned into compile errors pointing at the faulty attribute
+    pub fn to_syn_error(&self) -> syn::Error {
+        match self {
+            ValidationError::DtoNameDuplicated(span, message)
+            | ValidationError::MissingPropertyError(span, message)
+            | ValidationError::IgnoredFieldNotFound(span, message)
+            | ValidationError::MapIgnoreConflict(span, message)
+            | ValidationError::NewFieldCollision(span, message)
+            | ValidationError::NewFieldDuplicated(span, message)
+            | ValidationError::DtoNameIsStructName(span, message)
+            | ValidationError::InvalidDtoName(span, message)
+            | ValidationError::InvalidNewField(span, message)
+            | ValidationError::ExactlyWithMap(span, message)
+            | ValidationError::PatternMatchesNothing(span, message)
+            | ValidationError::PatternRenamed(span, message)
+            | ValidationError::UnknownGroup(span, message)
+            | ValidationError::InvalidConversion(span, message)
+            | ValidationError::UnaccountedField(span, message)
+            | ValidationError::SensitiveField(span, message)
+            | ValidationError::RedactedField(span, message)
+            | ValidationError::ZeroizedField(span, message) => {
+                syn::Error::new(span.unwrap_or_else(Span::call_site), message)
+            }
+            //each field error is reported on the attribute of its mapper entry
+            ValidationError::MapperEntryError(errors)
+            | ValidationError::StructEntryError(errors) => errors
+                .iter()
+                .map(FieldError::to_syn_error)
+                .reduce(|mut combined, error| {
+                    combined.combine(error);
+                    combined
+                })
+                .unwrap_or_else(|| syn::Error::new(Span::call_site(), "invalid mapper entries")),
+        }
+    }
 }
 
 pub fn validate_entry_data(
@@ -26,9 +101,288 @@ pub fn validate_entry_data(
     mp_entries: &Vec<MapperEntry>,
 ) -> Result<(), ValidationError> {
     validate_mapper_entries(&mp_entries)?;
+    validate_entry_fields(st_entry, mp_entries)?;
     validate_struct_entry(st_entry, &mp_entries)?;
+    validate_dto_fields(st_entry, mp_entries)?;
     validate_dto_name(&mp_entries)?;
     validate_map_ignore(&mp_entries)?;
+    validate_conversions(st_entry, mp_entries)?;
+    Ok(())
+}
+
+//checks each mapper entry against the struct fields and its own map, ignore and new_fields lists
+fn validate_entry_fields(
+    st_entry: &StructEntry,
+    mp_entries: &[MapperEntry],
+) -> Result<(), ValidationError> {
+    for mp_entry in mp_entries {
+        if let Err(error) = syn::parse_str::<syn::Path>(&mp_entry.dto) {
+            return Err(ValidationError::InvalidDtoName(
+                mp_entry.span,
+                format!(
+                    "dto name \"{}\" isn't a type name or path : {}",
+                    mp_entry.dto, error
+                ),
+            ));
+        }
+
+        if mp_entry.dto == st_entry.name {
+            return Err(ValidationError::DtoNameIsStructName(
+                mp_entry.span,
+                format!(
+                    "dto name `{}` is the name of the source structure, it must be different",
+                    mp_entry.dto
+                ),
+            ));
+        }
+
+        if mp_entry.exactly && !mp_entry.map.is_empty() {
+            return Err(ValidationError::ExactlyWithMap(
+                mp_entry.span,
+                format!(
+                    "dto={} uses `exactly=true` which maps every field of {} and can't be combined with `map`",
+                    mp_entry.dto, st_entry.name
+                ),
+            ));
+        }
+
+        //a misspelled ignored field would silently be exposed by the dto
+        let field_names: Vec<&str> = st_entry
+            .field_entries
+            .iter()
+            .map(|field| field.field_name.as_str())
+            .collect();
+        if let Some(ignored) = mp_entry.ignore.iter().find(|ignored| {
+            !field_names
+                .iter()
+                .any(|field_name| glob_match(ignored, field_name))
+        }) {
+            let message = if is_glob(ignored) {
+                format!(
+                    "dto={} ignores pattern `{}` matching no field of {}",
+                    mp_entry.dto, ignored, st_entry.name
+                )
+            } else {
+                format!(
+                    "dto={} ignores `{}` not found in {}{}",
+                    mp_entry.dto,
+                    ignored,
+                    st_entry.name,
+                    did_you_mean(ignored, field_names.iter().copied())
+                )
+            };
+            return Err(ValidationError::IgnoredFieldNotFound(
+                mp_entry.span,
+                message,
+            ));
+        }
+
+        //type selectors must select at least a field
+        let type_selectors = mp_entry
+            .ignore_types
+            .iter()
+            .map(|pattern| ("ignore_types", pattern))
+            .chain(
+                mp_entry
+                    .include_types
+                    .iter()
+                    .map(|pattern| ("include_types", pattern)),
+            );
+        for (property, pattern) in type_selectors {
+            if !st_entry
+                .field_entries
+                .iter()
+                .any(|field| field.matches_type(pattern))
+            {
+                return Err(ValidationError::PatternMatchesNothing(
+                    mp_entry.span,
+                    format!(
+                        "dto={} has `{}` pattern `{}` matching no field type of {}",
+                        mp_entry.dto, property, pattern, st_entry.name
+                    ),
+                ));
+            }
+        }
+
+        //a misspelled group would silently select nothing
+        let declared_groups: HashSet<&str> = st_entry
+            .field_entries
+            .iter()
+            .flat_map(|field| field.groups.iter().map(|group| group.as_str()))
+            .collect();
+        if let Some(group) = mp_entry
+            .include_groups
+            .iter()
+            .chain(mp_entry.exclude_groups.iter())
+            .find(|group| !declared_groups.contains(group.as_str()))
+        {
+            return Err(ValidationError::UnknownGroup(
+                mp_entry.span,
+                format!(
+                    "dto={} selects group `{}` which no field of {} belongs to{}",
+                    mp_entry.dto,
+                    group,
+                    st_entry.name,
+                    did_you_mean(group, declared_groups.iter().copied())
+                ),
+            ));
+        }
+
+        if let Some(mapped) = mp_entry
+            .map
+            .iter()
+            .find(|mp_value| is_glob(&mp_value.from_field) && mp_value.to_field.is_some())
+        {
+            return Err(ValidationError::PatternRenamed(
+                mp_entry.span,
+                format!(
+                    "dto={} can't rename the fields matching pattern `{}`",
+                    mp_entry.dto, mapped.from_field
+                ),
+            ));
+        }
+
+        //a field named in map can't be excluded by ignore or ignore_types
+        let ignore_fields = get_ignore_fields(st_entry, mp_entry);
+        if let Some(mapped) = mp_entry.map.iter().find(|mp_value| {
+            !is_glob(&mp_value.from_field) && ignore_fields.contains(&mp_value.from_field)
+        }) {
+            return Err(ValidationError::MapIgnoreConflict(
+                mp_entry.span,
+                format!(
+                    "dto={} both maps and ignores field `{}`",
+                    mp_entry.dto, mapped.from_field
+                ),
+            ));
+        }
+
+        let mut new_field_names: HashSet<&str> = HashSet::new();
+        for new_field in mp_entry.new_fields.iter() {
+            let invalid_part = syn::parse_str::<syn::Ident>(&new_field.field_name)
+                .err()
+                .map(|error| ("name", &new_field.field_name, error))
+                .or_else(|| {
+                    syn::parse_str::<syn::Type>(&new_field.field_type)
+                        .err()
+                        .map(|error| ("type", &new_field.field_type, error))
+                })
+                .or_else(|| {
+                    syn::parse_str::<syn::Expr>(&new_field.expression_value)
+                        .err()
+                        .map(|error| ("expression", &new_field.expression_value, error))
+                });
+            if let Some((part, value, error)) = invalid_part {
+                return Err(ValidationError::InvalidNewField(
+                    mp_entry.span,
+                    format!(
+                        "new field `{}` of dto={} has an invalid {} \"{}\" : {}",
+                        new_field.field_name, mp_entry.dto, part, value, error
+                    ),
+                ));
+            }
+            if !new_field_names.insert(new_field.field_name.as_str()) {
+                return Err(ValidationError::NewFieldDuplicated(
+                    mp_entry.span,
+                    format!(
+                        "dto={} declares new field `{}` more than once",
+                        mp_entry.dto, new_field.field_name
+                    ),
+                ));
+            }
+        }
+
+        if let Some(dto_field) = get_dto_fields(st_entry, mp_entry)
+            .iter()
+            .find(|dto_field| new_field_names.contains(dto_field.name.as_str()))
+        {
+            return Err(ValidationError::NewFieldCollision(
+                mp_entry.span,
+                format!(
+                    "dto={} declares new field `{}` which is already a field mapped from `{}`",
+                    mp_entry.dto, dto_field.name, dto_field.source.field_name
+                ),
+            ));
+        }
+    }
+    Ok(())
+}
+
+//a dto can only convert into a sibling dto whose fields it all has
+fn validate_conversions(
+    st_entry: &StructEntry,
+    mp_entries: &[MapperEntry],
+) -> Result<(), ValidationError> {
+    for mp_entry in mp_entries {
+        let source_fields = get_dto_fields(st_entry, mp_entry);
+        for target in mp_entry.convert_to.iter() {
+            let invalid_conversion = |reason: String| {
+                Err(ValidationError::InvalidConversion(
+                    mp_entry.span,
+                    format!(
+                        "dto={} can't convert into `{}` because {}",
+                        mp_entry.dto, target, reason
+                    ),
+                ))
+            };
+            let Some(target_entry) = mp_entries.iter().find(|t| &t.dto == target) else {
+                return invalid_conversion(format!(
+                    "it isn't a dto of {}{}",
+                    st_entry.name,
+                    did_you_mean(target, mp_entries.iter().map(|t| t.dto.as_str()))
+                ));
+            };
+            if target_entry.dto == mp_entry.dto {
+                return invalid_conversion("it is the same dto".to_string());
+            }
+            if mp_entry.extends.as_ref() == Some(target) {
+                return invalid_conversion(
+                    "it extends it and the conversion is already generated".to_string(),
+                );
+            }
+
+            let mut missing_fields: Vec<String> = get_dto_fields(st_entry, target_entry)
+                .iter()
+                .filter(|target_field| target_field.find_counterpart(&source_fields).is_none())
+                .map(|target_field| target_field.name.to_string())
+                .collect();
+            target_entry
+                .new_fields
+                .iter()
+                .filter(|target_field| {
+                    !mp_entry.new_fields.iter().any(|source_field| {
+                        source_field.field_name == target_field.field_name
+                            && remove_white_space(&source_field.field_type)
+                                == remove_white_space(&target_field.field_type)
+                    })
+                })
+                .for_each(|target_field| missing_fields.push(target_field.field_name.to_string()));
+            if !missing_fields.is_empty() {
+                return invalid_conversion(format!("it lacks the fields {:?}", missing_fields));
+            }
+
+            let unwrapped_fields: Vec<String> = get_dto_fields(st_entry, target_entry)
+                .iter()
+                .filter(|target_field| target_field.is_reversible())
+                .filter(|target_field| {
+                    target_field
+                        .find_counterpart(&source_fields)
+                        .is_some_and(|source_field| unwraps_option(source_field, target_field))
+                        && target_field
+                            .map_value
+                            .as_ref()
+                            .and_then(|m_value| m_value.unwrap_or.as_ref())
+                            .is_none()
+                })
+                .map(|target_field| target_field.name.to_string())
+                .collect();
+            if !unwrapped_fields.is_empty() {
+                return invalid_conversion(format!(
+                    "it unwraps the Option of the fields {:?} without unwrap_or",
+                    unwrapped_fields
+                ));
+            }
+        }
+    }
     Ok(())
 }
 
@@ -36,20 +390,28 @@ fn validate_map_ignore(mp_entries: &Vec<MapperEntry>) -> Result<(), ValidationEr
     //There should be at least a map attribute or an ignore attribute per mapper entry
     // valid mapper entry = ignore.len() > 0 || map.len() > 0
     // invalid mapper entry = ignore.len() == 0 and map.len()==0
-    // except if they has exactly=true
-    let invalid_entries: Vec<String> = mp_entries
+    // except if they has exactly=true, include_all=true, type or group selectors
+    let invalid_entries: Vec<&MapperEntry> = mp_entries
         .iter()
         .filter(|mp_entry| {
             return mp_entry.map.len() == 0
                 && mp_entry.ignore.len() == 0
-                && mp_entry.exactly == false;
+                && mp_entry.exactly == false
+                && !mp_entry.include_all
+                && mp_entry.ignore_types.is_empty()
+                && mp_entry.include_types.is_empty()
+                && mp_entry.include_groups.is_empty()
+                && mp_entry.exclude_groups.is_empty();
         })
-        .map(|mp_entry| mp_entry.dto.to_string())
         .collect();
 
-    if invalid_entries.len() > 0 {
+    if let Some(mp_entry) = invalid_entries.first() {
         return Err(ValidationError::MissingPropertyError(
-            "mapper requires a `map` or an `ignore` property".to_string(),
+            mp_entry.span,
+            format!(
+                "mapper of dto={} requires a `map`, an `ignore` or an `include_all` property",
+                mp_entry.dto
+            ),
         ));
     }
 
@@ -67,12 +429,66 @@ fn validate_dto_name(mp_entries: &Vec<MapperEntry>) -> Result<(), ValidationErro
     });
 
     let dto_dup: Vec<String> = map_hashmap_to_vec_string(&mut dto_hash);
-    if dto_dup.len() > 0 {
-        return Err(ValidationError::DtoNameDuplicated(dto_dup));
+    //the error points at the last mapper entry declaring a duplicated dto
+    if let Some(mp_entry) = mp_entries
+        .iter()
+        .rev()
+        .find(|mp_entry| dto_dup.contains(&mp_entry.dto))
+    {
+        return Err(ValidationError::DtoNameDuplicated(
+            mp_entry.span,
+            format!("dto={} is declared by several mapper entries", mp_entry.dto),
+        ));
     }
     Ok(())
 }
 
+//the tuples of a fanned out field need distinct dto fields and a single primary tuple without convert,
+//from which the struct field is converted back
+pub fn validate_fanout(mp_entry: &MapperEntry, from_field: &str) -> Option<String> {
+    if is_glob(from_field) {
+        return Some(format!(
+            "duplicate source key names found in dto={} entry: {:?}",
+            mp_entry.dto, from_field
+        ));
+    }
+    let tuples: Vec<&MapValue> = mp_entry
+        .map
+        .iter()
+        .filter(|m_value| m_value.from_field == from_field)
+        .collect();
+    let mut names: HashSet<&str> = HashSet::new();
+    if let Some(m_value) = tuples
+        .iter()
+        .find(|m_value| !names.insert(m_value.dto_name()))
+    {
+        return Some(format!(
+            "dto={} maps `{}` several times into the same field `{}`, rename them such as (\"{}:other_{}\", true)",
+            mp_entry.dto,
+            from_field,
+            m_value.dto_name(),
+            from_field,
+            from_field
+        ));
+    }
+    let primaries: Vec<&&MapValue> = tuples.iter().filter(|m_value| m_value.primary).collect();
+    match primaries.as_slice() {
+        [primary] if primary.convert.is_some() => Some(format!(
+            "dto={} converts its primary field `{}` with `convert` so `{}` can't be converted back from it",
+            mp_entry.dto,
+            primary.dto_name(),
+            from_field
+        )),
+        [_] => None,
+        _ => Some(format!(
+            "dto={} maps `{}` {} times and needs exactly one of them marked with `primary` to convert it back",
+            mp_entry.dto,
+            from_field,
+            tuples.len()
+        )),
+    }
+}
+
 fn map_hashmap_to_vec_string(dto_hash: &mut HashMap<String, u8>) -> Vec<String> {
     dto_hash
         .iter()
@@ -94,18 +510,47 @@ fn validate_struct_entry(
 
     let mut errors: Vec<FieldError> = Vec::new();
     for ref mp_entry in mp_entries {
-        let missing_fields: Vec<String> = mp_entry
+        mp_entry
             .map
             .iter()
+            //a mapped field with a cfg predicate may have been cfg-ed out of the struct
+            .filter(|&mp_value| mp_value.cfg.is_none())
+            .filter(|&mp_value| !is_glob(&mp_value.from_field))
             .filter(|&mp_value| !field_set.contains(&mp_value.from_field))
-            .map(|m| m.from_field.as_str().to_string())
-            .collect();
-        if missing_fields.len() > 0 {
-            errors.push(FieldError::MissingField(format!(
-                "{} field name doesn't exist in structure={}. List of wrong map field names : {:?}",
-                mp_entry.dto, st_entry.name, missing_fields
-            )));
-        }
+            .for_each(|mp_value| {
+                errors.push(FieldError::MissingField(
+                    mp_entry.span,
+                    format!(
+                        "dto={} maps `{}` not found in {}{}",
+                        mp_entry.dto,
+                        mp_value.from_field,
+                        st_entry.name,
+                        did_you_mean(&mp_value.from_field, field_set.iter().map(|f| f.as_str()))
+                    ),
+                ));
+            });
+
+        mp_entry
+            .map
+            .iter()
+            .filter(|&mp_value| is_glob(&mp_value.from_field))
+            .filter(|&mp_value| {
+                !field_set
+                    .iter()
+                    .any(|field_name| glob_match(&mp_value.from_field, field_name))
+            })
+            .for_each(|mp_value| {
+                errors.push(FieldError::MissingField(
+                    mp_entry.span,
+                    format!(
+                        "dto={} maps pattern `{}` matching no field of {}",
+                        mp_entry.dto, mp_value.from_field, st_entry.name
+                    ),
+                ));
+            });
+
+        errors.extend(validate_unwrap_options(st_entry, mp_entry));
+        errors.extend(validate_convert_options(mp_entry));
     }
     //println!("Validation Error : {:?}", errors);
     if errors.len() > 0 {
@@ -114,6 +559,237 @@ fn validate_struct_entry(
     Ok(())
 }
 
+//redacted fields get a generated Debug implementation which conflicts with a derived one,
+//and each redacted name must be a field of the dto
+fn validate_redacted_fields(
+    st_entry: &StructEntry,
+    mp_entry: &MapperEntry,
+) -> Result<(), ValidationError> {
+    let redacted_fields = mp_entry.get_redacted_fields();
+    if redacted_fields.is_empty() {
+        return Ok(());
+    }
+
+    if mp_entry.derive.iter().any(|derive| derive == "Debug") {
+        return Err(ValidationError::RedactedField(
+            mp_entry.span,
+            format!(
+                "dto={} has redacted fields and can't derive Debug, a redacting Debug implementation is generated",
+                mp_entry.dto
+            ),
+        ));
+    }
+
+    let dto_fields = get_dto_field_names(st_entry, mp_entry);
+    let unknown_fields: Vec<String> = redacted_fields
+        .into_iter()
+        .filter(|name| !dto_fields.contains(name))
+        .collect();
+    if !unknown_fields.is_empty() {
+        return Err(ValidationError::RedactedField(
+            mp_entry.span,
+            format!(
+                "dto={} redacts fields that are not part of it : {:?}",
+                mp_entry.dto, unknown_fields
+            ),
+        ));
+    }
+    Ok(())
+}
+
+//each zeroized name must be a field of the dto
+fn validate_zeroized_fields(
+    st_entry: &StructEntry,
+    mp_entry: &MapperEntry,
+) -> Result<(), ValidationError> {
+    let dto_fields = get_dto_field_names(st_entry, mp_entry);
+    let unknown_fields: Vec<String> = mp_entry
+        .zeroize
+        .iter()
+        .filter(|name| !dto_fields.contains(name))
+        .map(|name| name.to_string())
+        .collect();
+
+    if unknown_fields.is_empty() {
+        return Ok(());
+    }
+    Err(ValidationError::ZeroizedField(
+        mp_entry.span,
+        format!(
+            "dto={} zeroizes fields that are not part of it : {:?}",
+            mp_entry.dto, unknown_fields
+        ),
+    ))
+}
+
+//names of the dto fields, including the new fields
+fn get_dto_field_names(st_entry: &StructEntry, mp_entry: &MapperEntry) -> Vec<String> {
+    let mut dto_fields: Vec<String> = get_dto_fields(st_entry, mp_entry)
+        .iter()
+        .map(|dto_field| dto_field.name.to_string())
+        .collect();
+    mp_entry
+        .new_fields
+        .iter()
+        .for_each(|new_field| dto_fields.push(new_field.field_name.to_string()));
+    dto_fields
+}
+
+//sensitive fields are either marked with #[mapper(sensitive)] or match a sensitive pattern of the entry.
+//They are denied by default and a dto must list them in allow_sensitive to include them
+pub fn validate_sensitive_fields(
+    st_entry: &StructEntry,
+    mp_entry: &MapperEntry,
+) -> Result<(), ValidationError> {
+    let exposed_fields: Vec<String> = get_dto_fields(st_entry, mp_entry)
+        .iter()
+        .map(|dto_field| &dto_field.source)
+        .filter(|field| {
+            field.is_sensitive
+                || mp_entry
+                    .sensitive
+                    .iter()
+                    .any(|pattern| glob_match(pattern, &field.field_name))
+        })
+        .filter(|field| !mp_entry.allow_sensitive.contains(&field.field_name))
+        .map(|field| field.field_name.to_string())
+        .collect();
+
+    if exposed_fields.is_empty() {
+        return Ok(());
+    }
+    Err(ValidationError::SensitiveField(
+        mp_entry.span,
+        format!(
+            "dto={} exposes sensitive fields of structure={} : {:?}. Ignore them or add them to `allow_sensitive`",
+            mp_entry.dto, st_entry.name, exposed_fields
+        ),
+    ))
+}
+
+//checks the fields each dto ends up with once the struct fields are known to exist
+fn validate_dto_fields(
+    st_entry: &StructEntry,
+    mp_entries: &[MapperEntry],
+) -> Result<(), ValidationError> {
+    for mp_entry in mp_entries {
+        validate_exhaustive(st_entry, mp_entry)?;
+        validate_sensitive_fields(st_entry, mp_entry)?;
+        validate_redacted_fields(st_entry, mp_entry)?;
+        validate_zeroized_fields(st_entry, mp_entry)?;
+    }
+    Ok(())
+}
+
+//an exhaustive mapper entry must list each struct field in map or ignore so that a new struct field
+//doesn't silently leak into the dto or vanish from it, include_all too. exactly already maps every struct field
+fn validate_exhaustive(
+    st_entry: &StructEntry,
+    mp_entry: &MapperEntry,
+) -> Result<(), ValidationError> {
+    if !mp_entry.exhaustive || mp_entry.exactly {
+        return Ok(());
+    }
+
+    let ignore_fields = get_ignore_fields(st_entry, mp_entry);
+    let map_fields = get_map_of_mapvalue(st_entry, mp_entry);
+    let unaccounted_fields: Vec<String> = st_entry
+        .field_entries
+        .iter()
+        .map(|f| f.field_name.to_string())
+        .filter(|name| !ignore_fields.contains(name) && !map_fields.contains_key(name))
+        .collect();
+
+    if unaccounted_fields.is_empty() {
+        return Ok(());
+    }
+    Err(ValidationError::UnaccountedField(
+        mp_entry.span,
+        format!(
+            "dto={} is exhaustive but fields of structure={} are neither in `map` nor in `ignore` : {:?}",
+            mp_entry.dto, st_entry.name, unaccounted_fields
+        ),
+    ))
+}
+
+//a converted field has the type returned by its convert function, which can't be unwrapped.
+//convert must be a function path and ty a type
+fn validate_convert_options(mp_entry: &MapperEntry) -> Vec<FieldError> {
+    mp_entry
+        .map
+        .iter()
+        .filter_map(|m_value| {
+            let reason = match (&m_value.convert, &m_value.ty) {
+                (Some(_), _) if m_value.unwrap => {
+                    "`convert` can't be combined with `unwrap`".to_string()
+                }
+                (None, Some(_)) => {
+                    "`ty` is the type returned by `convert` which is missing".to_string()
+                }
+                (Some(convert), _) if syn::parse_str::<syn::Path>(convert).is_err() => {
+                    format!("`convert` \"{}\" isn't a function path", convert)
+                }
+                (_, Some(ty)) if syn::parse_str::<syn::Type>(ty).is_err() => {
+                    format!("`ty` \"{}\" isn't a type", ty)
+                }
+                _ => return None,
+            };
+            Some(FieldError::InvalidOption(
+                mp_entry.span,
+                format!(
+                    "field `{}` of dto={} is invalid because {}",
+                    m_value.dto_name(),
+                    mp_entry.dto,
+                    reason
+                ),
+            ))
+        })
+        .collect()
+}
+
+//unwrap can only be used on a required Option<T> field whose inner type T is known,
+//and the unwrap_or fallback must be an expression
+fn validate_unwrap_options(st_entry: &StructEntry, mp_entry: &MapperEntry) -> Vec<FieldError> {
+    let invalid_fallbacks = mp_entry.map.iter().filter_map(|m_value| {
+        let fallback = m_value.unwrap_or.as_ref()?;
+        let error = syn::parse_str::<syn::Expr>(fallback).err()?;
+        Some(FieldError::InvalidOption(
+            mp_entry.span,
+            format!(
+                "field `{}` of dto={} has an invalid unwrap_or expression \"{}\": {}",
+                m_value.dto_name(),
+                mp_entry.dto,
+                fallback,
+                error
+            ),
+        ))
+    });
+    get_dto_fields(st_entry, mp_entry)
+        .iter()
+        .filter_map(|dto_field| {
+            let mp_value = dto_field.map_value.as_ref().filter(|m| m.unwrap)?;
+            let field = &dto_field.source;
+            let reason = if !mp_value.required {
+                "it is mapped with required=false"
+            } else if !field.is_optional {
+                "it is not an Option"
+            } else if field.inner_type.is_none() {
+                "its type isn't written as Option<T>"
+            } else {
+                return None;
+            };
+            Some(FieldError::InvalidOption(
+                mp_entry.span,
+                format!(
+                    "field `{}` of dto={} can't be unwrapped because {}",
+                    field.field_name, mp_entry.dto, reason
+                ),
+            ))
+        })
+        .chain(invalid_fallbacks)
+        .collect()
+}
+
 fn validate_mapper_entries(mp_entries: &Vec<MapperEntry>) -> Result<(), ValidationError> {
     //verify if we have duplicate field names in mp_entry for source and destination map fields
 
@@ -150,28 +826,34 @@ fn validate_mapper_entries(mp_entries: &Vec<MapperEntry>) -> Result<(), Validati
             .collect();
 
         if dup_fields.len() > 0 {
-            errors.push(FieldError::DupField(format!(
-                "duplicate mapping destination keys found in dto={} entry: {:?}",
-                mp_entry.dto, dup_fields
-            )));
+            errors.push(FieldError::DupField(
+                mp_entry.span,
+                format!(
+                    "duplicate mapping destination keys found in dto={} entry: {:?}",
+                    mp_entry.dto, dup_fields
+                ),
+            ));
         }
 
+        //a struct field named in several map tuples fans out into several dto fields
         let dup_from: Vec<String> = map_hashmap_to_vec_string(&mut from_set);
-
-        if dup_from.len() > 0 {
-            errors.push(FieldError::DupField(format!(
-                "duplicate source key names found in dto={} entry: {:?}",
-                mp_entry.dto, dup_from
-            )));
-        }
+        errors.extend(
+            dup_from
+                .iter()
+                .filter_map(|from_field| validate_fanout(mp_entry, from_field))
+                .map(|message| FieldError::DupField(mp_entry.span, message)),
+        );
 
         let dup_to: Vec<String> = map_hashmap_to_vec_string(&mut to_set);
 
         if dup_to.len() > 0 {
-            errors.push(FieldError::DupField(format!(
-                "duplicate destination key names found in dto={} entry: {:?}",
-                mp_entry.dto, dup_to
-            )));
+            errors.push(FieldError::DupField(
+                mp_entry.span,
+                format!(
+                    "duplicate destination key names found in dto={} entry: {:?}",
+                    mp_entry.dto, dup_to
+                ),
+            ));
         }
     }
 
@@ -181,3 +863,311 @@ fn validate_mapper_entries(mp_entries: &Vec<MapperEntry>) -> Result<(), Validati
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::{parse_quote, DeriveInput};
+
+    fn validate(input: DeriveInput) -> String {
+        let st_entry = StructEntry::build_struct_entry(Box::new(input.clone())).unwrap();
+        let mp_entries: Vec<MapperEntry> = input
+            .attrs
+            .iter()
+            .map(|attr| MapperEntry::build(attr, None).unwrap())
+            .collect();
+        match validate_entry_data(&st_entry, &mp_entries) {
+            Ok(()) => String::new(),
+            Err(error) => error.to_syn_error().to_string(),
+        }
+    }
+
+    #[test]
+    fn test_ignored_pattern_matching_no_field() {
+        let message = validate(parse_quote! {
+            #[mapper(dto = "UserDto", ignore = ["internal_*"])]
+            struct User {
+                name: String,
+                email: String,
+            }
+        });
+        assert_eq!(
+            "dto=UserDto ignores pattern `internal_*` matching no field of User",
+            message
+        );
+    }
+    #[test]
+    fn test_ignored_field_typo_suggests_the_field() {
+        let message = validate(parse_quote! {
+            #[mapper(dto = "UserDto", ignore = ["emal"])]
+            struct User {
+                name: String,
+                email: String,
+            }
+        });
+        assert_eq!(
+            "dto=UserDto ignores `emal` not found in User, did you mean `email`?",
+            message
+        );
+    }
+    #[test]
+    fn test_mapped_field_typo_suggests_the_field() {
+        let message = validate(parse_quote! {
+            #[mapper(dto = "UserDto", map = [("nme", true)])]
+            struct User {
+                name: String,
+                email: String,
+            }
+        });
+        assert_eq!(
+            "dto=UserDto maps `nme` not found in User, did you mean `name`?",
+            message
+        );
+    }
+
+    #[test]
+    fn test_unwrap_of_a_plain_field() {
+        let message = validate(parse_quote! {
+            #[mapper(dto = "UserDto", map = [("name", true, unwrap)])]
+            struct User {
+                name: String,
+            }
+        });
+        assert_eq!(
+            "field `name` of dto=UserDto can't be unwrapped because it is not an Option",
+            message
+        );
+    }
+
+    #[test]
+    fn test_invalid_unwrap_or_expression() {
+        let message = validate(parse_quote! {
+            #[mapper(dto = "UserDto", map = [("age", true, unwrap_or = "18 +")])]
+            struct User {
+                age: Option<u8>,
+            }
+        });
+        assert!(
+            message.starts_with(
+                r#"field `age` of dto=UserDto has an invalid unwrap_or expression "18 +": "#
+            ),
+            "{}",
+            message
+        );
+    }
+
+    #[test]
+    fn test_invalid_convert_function() {
+        let message = validate(parse_quote! {
+            #[mapper(dto = "UserDto", map = [("name", true, convert = "to_x(")])]
+            struct User {
+                name: String,
+            }
+        });
+        assert_eq!(
+            r#"field `name` of dto=UserDto is invalid because `convert` "to_x(" isn't a function path"#,
+            message
+        );
+    }
+
+    #[test]
+    fn test_fanout_without_primary() {
+        let message = validate(parse_quote! {
+            #[mapper(dto = "UserDto", map = [("name", true), ("name:login", true)])]
+            struct User {
+                name: String,
+            }
+        });
+        assert_eq!(
+            "dto=UserDto maps `name` 2 times and needs exactly one of them marked with `primary` to convert it back",
+            message
+        );
+    }
+
+    #[test]
+    fn test_conversion_unwrapping_option_without_fallback() {
+        let message = validate(parse_quote! {
+            #[mapper(dto = "ContactDto", convert_to = ["PhoneDto"], map = [("phone", true)])]
+            #[mapper(dto = "PhoneDto", map = [("phone", true, unwrap)])]
+            struct User {
+                phone: Option<String>,
+            }
+        });
+        assert_eq!(
+            r#"dto=ContactDto can't convert into `PhoneDto` because it unwraps the Option of the fields ["phone"] without unwrap_or"#,
+            message
+        );
+    }
+
+    #[test]
+    fn test_exhaustive_entry_with_unlisted_field() {
+        let message = validate(parse_quote! {
+            #[mapper(dto = "UserDto", exhaustive = true, map = [("name", true)])]
+            struct User {
+                name: String,
+                api_token: String,
+            }
+        });
+        assert_eq!(
+            r#"dto=UserDto is exhaustive but fields of structure=User are neither in `map` nor in `ignore` : ["api_token"]"#,
+            message
+        );
+
+        let message = validate(parse_quote! {
+            #[mapper(dto = "UserDto", exhaustive = true, include_all = true, ignore = ["email"])]
+            struct User {
+                name: String,
+                email: String,
+                api_token: String,
+            }
+        });
+        assert_eq!(
+            r#"dto=UserDto is exhaustive but fields of structure=User are neither in `map` nor in `ignore` : ["name", "api_token"]"#,
+            message
+        );
+    }
+
+    #[test]
+    fn test_field_marked_sensitive_is_denied() {
+        let message = validate(parse_quote! {
+            #[mapper(dto = "UserDto", map = [("name", true), ("password", true)])]
+            struct User {
+                name: String,
+                #[mapper(sensitive)]
+                password: String,
+            }
+        });
+        assert_eq!(
+            r#"dto=UserDto exposes sensitive fields of structure=User : ["password"]. Ignore them or add them to `allow_sensitive`"#,
+            message
+        );
+    }
+
+    #[test]
+    fn test_field_matching_a_sensitive_pattern_is_denied() {
+        let message = validate(parse_quote! {
+            #[mapper(dto = "UserDto", include_all = true, sensitive = ["*token*"], ignore = ["password"])]
+            struct User {
+                name: String,
+                password: String,
+                api_token: String,
+            }
+        });
+        assert_eq!(
+            r#"dto=UserDto exposes sensitive fields of structure=User : ["api_token"]. Ignore them or add them to `allow_sensitive`"#,
+            message
+        );
+    }
+
+    #[test]
+    fn test_field_both_mapped_and_ignored() {
+        let message = validate(parse_quote! {
+            #[mapper(dto = "UserDto", map = [("name", true)], ignore = ["name"])]
+            struct User {
+                name: String,
+                email: String,
+            }
+        });
+        assert_eq!("dto=UserDto both maps and ignores field `name`", message);
+    }
+
+    #[test]
+    fn test_new_field_colliding_with_a_mapped_field() {
+        let message = validate(parse_quote! {
+            #[mapper(dto = "UserDto", map = [("email:contact", true)],
+                new_fields = [("contact: String", "String::new()")])]
+            struct User {
+                email: String,
+            }
+        });
+        assert_eq!(
+            "dto=UserDto declares new field `contact` which is already a field mapped from `email`",
+            message
+        );
+    }
+
+    #[test]
+    fn test_new_field_declared_twice() {
+        let message = validate(parse_quote! {
+            #[mapper(dto = "UserDto", map = [("email", true)],
+                new_fields = [("age: u8", "18"), ("age: u8", "21")])]
+            struct User {
+                email: String,
+            }
+        });
+        assert_eq!(
+            "dto=UserDto declares new field `age` more than once",
+            message
+        );
+    }
+
+    #[test]
+    fn test_dto_named_after_the_struct() {
+        let message = validate(parse_quote! {
+            #[mapper(dto = "User", map = [("email", true)])]
+            struct User {
+                email: String,
+            }
+        });
+        assert_eq!(
+            "dto name `User` is the name of the source structure, it must be different",
+            message
+        );
+    }
+
+    #[test]
+    fn test_exactly_combined_with_map() {
+        let message = validate(parse_quote! {
+            #[mapper(dto = "UserDto", exactly = true, map = [("email", true)])]
+            struct User {
+                email: String,
+            }
+        });
+        assert_eq!(
+            "dto=UserDto uses `exactly=true` which maps every field of User and can't be combined with `map`",
+            message
+        );
+    }
+
+    #[test]
+    fn test_dto_declared_twice() {
+        let message = validate(parse_quote! {
+            #[mapper(dto = "UserDto", map = [("email", true)])]
+            #[mapper(dto = "UserDto", map = [("name", true)])]
+            struct User {
+                name: String,
+                email: String,
+            }
+        });
+        assert_eq!("dto=UserDto is declared by several mapper entries", message);
+    }
+
+    #[test]
+    fn test_mapper_without_field_selection() {
+        let message = validate(parse_quote! {
+            #[mapper(dto = "UserDto", derive = (Debug))]
+            struct User {
+                email: String,
+            }
+        });
+        assert_eq!(
+            "mapper of dto=UserDto requires a `map`, an `ignore` or an `include_all` property",
+            message
+        );
+    }
+
+    #[test]
+    fn test_new_field_with_invalid_type() {
+        let message = validate(parse_quote! {
+            #[mapper(dto = "UserDto", map = [("email", true)], new_fields = [("age: u8<", "18")])]
+            struct User {
+                email: String,
+            }
+        });
+        assert!(
+            message.starts_with(r#"new field `age` of dto=UserDto has an invalid type "u8<" : "#),
+            "{}",
+            message
+        );
+    }
+}
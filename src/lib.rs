@@ -6,46 +6,80 @@ extern crate quote;
 #[cfg(test)]
 extern crate derive_builder;
 mod dto_builder;
+mod dto_composer;
+mod dto_parts;
 mod entry_validator;
 mod mapper_entry;
+mod mapper_profile;
 mod struct_entry;
 mod utils;
 
 //re-export dependencies
 
+use dto_composer::{is_composable, ComposeInput};
+use dto_parts::PartsEntry;
 use entry_validator::validate_entry_data;
 use mapper_entry::MapperEntry;
+use mapper_profile::ProfileRef;
 
 use proc_macro::TokenStream;
 use quote::quote;
 use struct_entry::StructEntry;
-use syn::{parse_macro_input, Attribute, DeriveInput};
+use syn::{parse::Parser, parse_macro_input, spanned::Spanned, Attribute, DeriveInput};
+use utils::did_you_mean;
 
 //https://developerlife.com/2022/03/30/rust-proc-macro/
 //https://astexplorer.net/
 //https://towardsdatascience.com/nine-rules-for-creating-procedural-macros-in-rust-595aa476a7ff
 
-#[proc_macro_derive(DtoMapper, attributes(mapper))]
+#[proc_macro_derive(DtoMapper, attributes(mapper, mapper_defaults))]
 pub fn dto_mapper_proc_macro(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
-    let input = Box::new(input);
+    expand_dto_mapper(Box::new(input), None).into()
+}
+
+//remote! declares dtos of a struct from another crate with a mirror of its fields, such as
+//remote!(#[mapper(remote = "billing::Invoice")] #[mapper(dto = "InvoiceDto", map = [("id", true)])] struct Invoice { id: u64 });
+#[proc_macro]
+pub fn remote(input: TokenStream) -> TokenStream {
+    let mut input = parse_macro_input!(input as DeriveInput);
+    match take_remote_path(&mut input) {
+        Ok(remote_path) => expand_dto_mapper(Box::new(input), Some(remote_path)),
+        Err(error) => error.to_compile_error(),
+    }
+    .into()
+}
 
-    let struct_entry = match process_struct_data(input.clone()) {
+//the mirror struct of remote! is never generated, the conversions use the remote type instead
+fn expand_dto_mapper(
+    input: Box<DeriveInput>,
+    remote: Option<syn::Path>,
+) -> proc_macro2::TokenStream {
+    let mut struct_entry = match process_struct_data(input.clone()) {
         Ok(st_entry) => st_entry,
-        Err(error) => {
-            panic!("Failed parsing structure entry with error: {} ", error)
-        }
+        Err(error) => return error.to_compile_error(),
     };
+    struct_entry.remote = remote;
 
-    let mapper_entries = match get_mapper_entries(input.clone()) {
+    let (mapper_entries, profile_files) = match get_mapper_entries(input.clone(), &struct_entry) {
         Ok(map_entries) => map_entries,
-        Err(error) => panic!("Error parsing mapper entries : {}", error),
+        Err(error) => return error.to_compile_error(),
     };
 
+    let mut mapper_entries = mapper_entries;
+    if let Err(error) = resolve_extended_entries(&mut mapper_entries, &struct_entry) {
+        return error.to_compile_error();
+    }
+
     if let Err(error) = validate_entry_data(&struct_entry, &mapper_entries) {
-        panic!("Failed Validating mapper entries with error : {:?}", error);
+        return error.to_syn_error().to_compile_error();
     }
 
+    let parts_entries = match get_parts_entries(&input, &struct_entry, &mapper_entries) {
+        Ok(parts_entries) => parts_entries,
+        Err(error) => return error.to_compile_error(),
+    };
+
     let dtos = dto_builder::generate_dto_stream(&mapper_entries, &struct_entry);
     let dto_stream = quote! {
         #(#dtos)*
@@ -55,6 +89,34 @@ pub fn dto_mapper_proc_macro(input: TokenStream) -> TokenStream {
 
     let struct_impls = dto_builder::generate_impl(&mapper_entries, &struct_entry, false);
 
+    let accessors = dto_builder::generate_accessors(&mapper_entries, &struct_entry);
+
+    let debug_impls = dto_builder::generate_debug_impls(&mapper_entries, &struct_entry);
+
+    let drop_impls = dto_builder::generate_drop_impls(&mapper_entries, &struct_entry);
+
+    let extends_impls = dto_builder::generate_extends_impls(&mapper_entries, &struct_entry);
+
+    let convert_impls = dto_builder::generate_convert_impls(&mapper_entries, &struct_entry);
+
+    let split_impls =
+        dto_parts::generate_split_impls(&parts_entries, &mapper_entries, &struct_entry);
+
+    let join_impls = dto_parts::generate_join_impls(&parts_entries, &mapper_entries, &struct_entry);
+
+    //only a struct marked with #[mapper(composable)] can be a source of compose!
+    let source_macro = match input.attrs.iter().find(|attr| is_composable(attr)) {
+        Some(attr) if struct_entry.remote.is_some() => {
+            return syn::Error::new(
+                attr.span(),
+                "`composable` can't be used in remote! since the definition of the struct isn't in the crate",
+            )
+            .to_compile_error();
+        }
+        Some(_) => dto_composer::generate_source_macro(&input),
+        None => quote! {},
+    };
+
     let expanded = quote! {
         //DTOs generated
         #dto_stream
@@ -62,10 +124,49 @@ pub fn dto_mapper_proc_macro(input: TokenStream) -> TokenStream {
         #(#dto_impls)*
 
         #(#struct_impls)*
+
+        #(#accessors)*
+
+        #(#debug_impls)*
+
+        #(#drop_impls)*
+
+        #(#extends_impls)*
+
+        #(#convert_impls)*
+
+        #(#split_impls)*
+
+        #(#join_impls)*
+
+        #(#profile_files)*
+
+        #source_macro
     };
 
     // println!("\n{:?}", expanded.to_string());
-    expanded.into()
+    expanded
+}
+
+//compose! declares a dto from several structs deriving DtoMapper, such as
+//compose!(#[mapper(dto="UserAccountDto", sources=["User", "Account"], map=[("User.name", true), ("Account.balance", true)])]);
+#[proc_macro]
+pub fn compose(input: TokenStream) -> TokenStream {
+    ComposeInput::parse_declaration
+        .parse(input)
+        .and_then(ComposeInput::expand)
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into()
+}
+
+//called back by the hidden macro of each source struct until all of them are collected
+#[doc(hidden)]
+#[proc_macro]
+pub fn __compose_sources(input: TokenStream) -> TokenStream {
+    parse_macro_input!(input as ComposeInput)
+        .expand()
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into()
 }
 
 fn process_struct_data(input: Box<DeriveInput>) -> syn::Result<StructEntry> {
@@ -73,25 +174,180 @@ fn process_struct_data(input: Box<DeriveInput>) -> syn::Result<StructEntry> {
 }
 
 const MAPPER: &'static str = "mapper";
+const MAPPER_DEFAULTS: &str = "mapper_defaults";
+const REMOTE: &str = "remote";
+
+//removes the #[mapper(remote = "path")] attribute of the mirror struct and returns the path
+fn take_remote_path(input: &mut DeriveInput) -> syn::Result<syn::Path> {
+    let is_remote = |attr: &Attribute| {
+        attr.path().is_ident(MAPPER)
+            && attr
+                .parse_args::<syn::MetaNameValue>()
+                .is_ok_and(|meta| meta.path.is_ident(REMOTE))
+    };
+    let Some(index) = input.attrs.iter().position(is_remote) else {
+        return Err(syn::Error::new(
+            input.ident.span(),
+            "remote! needs a #[mapper(remote = \"path::to::Struct\")] attribute naming the struct it mirrors",
+        ));
+    };
+    let attr = input.attrs.remove(index);
+    let meta = attr.parse_args::<syn::MetaNameValue>()?;
+    match meta.value {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Str(ref lit_str),
+            ..
+        }) => lit_str.parse(),
+        _ => Err(syn::Error::new(
+            meta.value.span(),
+            "`remote` must be a string literal such as \"billing::Invoice\"",
+        )),
+    }
+}
 
-fn get_mapper_entries(input: Box<DeriveInput>) -> syn::Result<Vec<MapperEntry>> {
+//returns the mapper entries along with the tracking of the profiles files they were loaded from
+fn get_mapper_entries(
+    input: Box<DeriveInput>,
+    struct_entry: &StructEntry,
+) -> syn::Result<(Vec<MapperEntry>, Vec<proc_macro2::TokenStream>)> {
     let mapper_attrs: Vec<&Attribute> = input
         .attrs
         .iter()
         .filter(|&a| a.path().is_ident(MAPPER))
         .collect::<Vec<&Attribute>>();
 
+    //#[mapper_defaults(...)] holds properties shared by every mapper entry
+    let mut defaults_attrs = input
+        .attrs
+        .iter()
+        .filter(|&a| a.path().is_ident(MAPPER_DEFAULTS));
+    let defaults_attr = defaults_attrs.next();
+    if let Some(duplicated_attr) = defaults_attrs.next() {
+        return Err(syn::Error::new(
+            duplicated_attr.span(),
+            "`mapper_defaults` attribute can only be declared once",
+        ));
+    }
+
     let mut mapper_entries: Vec<MapperEntry> = Vec::new();
+    let mut profile_files: Vec<proc_macro2::TokenStream> = Vec::new();
 
     for attr in mapper_attrs {
+        //#[mapper(profile = "crud")] expands into the mapper entries of the profile
+        if let Some(profile) = ProfileRef::build(attr)? {
+            for profile_attr in profile.load_attributes(&struct_entry.name)? {
+                let mut mapper_entry = MapperEntry::build(&profile_attr, defaults_attr)?;
+                mapper_entry.span = Some(profile.span);
+                mapper_entry.retain_struct_fields(struct_entry);
+                mapper_entries.push(mapper_entry);
+            }
+            profile_files.push(profile.track_file());
+            continue;
+        }
+        //#[mapper(split = [...])] and #[mapper(join = [...])] are handled by get_parts_entries
+        if PartsEntry::build(attr)?.is_some() || is_composable(attr) {
+            continue;
+        }
         //println!("=======MapperEntry===============");
-        let build_result = MapperEntry::build(attr);
-        if let Ok(mapper_entry) = build_result {
-            //println!("{:?}",mapper_entry);
-            mapper_entries.push(mapper_entry);
-        } else if let Err(error) = build_result {
-            panic!("Error parsing mapper entry: {:?}", error)
+        let mapper_entry = MapperEntry::build(attr, defaults_attr)?;
+        //println!("{:?}",mapper_entry);
+        mapper_entries.push(mapper_entry);
+    }
+    syn::Result::Ok((mapper_entries, profile_files))
+}
+
+//split and join declarations are checked against the resolved mapper entries, a struct has a single
+//into_parts and a single from_parts
+fn get_parts_entries(
+    input: &DeriveInput,
+    struct_entry: &StructEntry,
+    mapper_entries: &[MapperEntry],
+) -> syn::Result<Vec<PartsEntry>> {
+    let mut parts_entries: Vec<PartsEntry> = Vec::new();
+    for attr in input.attrs.iter().filter(|&a| a.path().is_ident(MAPPER)) {
+        let Some(parts) = PartsEntry::build(attr)? else {
+            continue;
+        };
+        if struct_entry.remote.is_some() {
+            return Err(syn::Error::new(
+                parts.span,
+                format!(
+                    "`{}` can't be used in remote! since methods can't be added to a type of another crate",
+                    parts.name()
+                ),
+            ));
+        }
+        if parts_entries.iter().any(|other| other.kind == parts.kind) {
+            return Err(syn::Error::new(
+                parts.span,
+                format!("`{}` can only be declared once per struct", parts.name()),
+            ));
+        }
+        parts.validate(struct_entry, mapper_entries)?;
+        parts_entries.push(parts);
+    }
+    Ok(parts_entries)
+}
+
+//an entry extending another dto is resolved once the dto it extends is resolved itself
+fn resolve_extended_entries(
+    mapper_entries: &mut [MapperEntry],
+    struct_entry: &StructEntry,
+) -> syn::Result<()> {
+    let mut resolved: Vec<bool> = mapper_entries
+        .iter()
+        .map(|mp_entry| mp_entry.extends.is_none())
+        .collect();
+
+    while let Some(unresolved) = resolved.iter().position(|&is_resolved| !is_resolved) {
+        let mut has_progressed = false;
+        for index in unresolved..mapper_entries.len() {
+            if resolved[index] {
+                continue;
+            }
+            let mp_entry = &mapper_entries[index];
+            let parent_name = mp_entry.extends.clone().unwrap_or_default();
+            let Some(parent_index) = mapper_entries
+                .iter()
+                .position(|parent| parent.dto == parent_name)
+            else {
+                return Err(syn::Error::new(
+                    mp_entry.span.unwrap_or_else(proc_macro2::Span::call_site),
+                    format!(
+                        "dto={} extends `{}` which isn't a dto of {}{}",
+                        mp_entry.dto,
+                        parent_name,
+                        struct_entry.name,
+                        did_you_mean(
+                            &parent_name,
+                            mapper_entries
+                                .iter()
+                                .filter(|parent| parent.dto != mp_entry.dto)
+                                .map(|parent| parent.dto.as_str())
+                        )
+                    ),
+                ));
+            };
+            if !resolved[parent_index] {
+                continue;
+            }
+            let parent = std::mem::take(&mut mapper_entries[parent_index]);
+            mapper_entries[index].extend_from(&parent, struct_entry);
+            mapper_entries[parent_index] = parent;
+            resolved[index] = true;
+            has_progressed = true;
+        }
+
+        if !has_progressed {
+            let mp_entry = &mapper_entries[unresolved];
+            return Err(syn::Error::new(
+                mp_entry.span.unwrap_or_else(proc_macro2::Span::call_site),
+                format!(
+                    "dto={} can't be resolved because the dtos it extends form a cycle",
+                    mp_entry.dto
+                ),
+            ));
         }
     }
-    syn::Result::Ok(mapper_entries)
+    Ok(())
 }
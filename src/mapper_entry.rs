@@ -1,22 +1,65 @@
 use syn::{
-    punctuated::Punctuated, spanned::Spanned, Attribute, Expr, ExprArray, ExprLit, ExprTuple, Lit,
-    Meta, Token,
+    punctuated::Punctuated, spanned::Spanned, Attribute, Expr, ExprArray, ExprLit, Lit, Meta, Token,
 };
 
+use proc_macro2::Span;
+
+use crate::dto_builder::{get_dto_fields, get_ignore_fields, get_map_of_mapvalue};
+use crate::struct_entry::StructEntry;
 use crate::utils;
 use crate::utils::isblank;
 
-pub type MapTuple = (String, bool, Vec<String>);
+//options of a map tuple such as forward_attrs=["serde"] with the span of their key. A bare flag option has no value
+pub type MapOption = (String, Option<Expr>, Span);
+pub type MapTuple = (String, bool, Vec<String>, Vec<MapOption>);
 #[derive(Debug, Default)]
 pub struct MapperEntry {
     pub dto: String,
     pub map: Vec<MapValue>,
     pub ignore: Vec<String>,
+    //type selectors such as "Secret<_>" excluding or including struct fields by type
+    pub ignore_types: Vec<String>,
+    pub include_types: Vec<String>,
+    //groups declared on struct fields with #[mapper(groups("public"))]
+    pub include_groups: Vec<String>,
+    pub exclude_groups: Vec<String>,
     pub derive: Vec<String>,
     pub no_builder: bool,
     pub new_fields: Vec<NewField>,
     pub exactly: bool,
+    //every struct field is included except the ignored ones, map tuples override them
+    pub include_all: bool,
+    //every struct field must be listed either in map or ignore
+    pub exhaustive: bool,
     pub macro_attr: Vec<String>,
+    //glob patterns of struct field names considered as sensitive, such as "*password*"
+    pub sensitive: Vec<String>,
+    pub allow_sensitive: Vec<String>,
+    //dto fields printed as <redacted> by a generated Debug implementation
+    pub redact: Vec<String>,
+    //dto fields wiped from memory when the dto is dropped, requires the zeroize feature
+    pub zeroize: Vec<String>,
+    pub forward_attrs: Vec<String>,
+    pub forward_exclude: Vec<String>,
+    pub getters: bool,
+    pub setters: bool,
+    pub field_vis: Option<String>,
+    pub doc: Option<String>,
+    //cfg predicate such as feature = "billing" under which the dto exists
+    pub cfg: Option<String>,
+    //false when the dto is an existing type such as the target of the entry, only conversions are generated
+    pub generate: bool,
+    //path of an existing type such as "api::LoginPayload" used as the dto
+    pub target: Option<String>,
+    //sibling dtos of the struct this dto converts into directly
+    pub convert_to: Vec<String>,
+    //name of another dto of the struct whose fields, renames, attributes, derives and new fields are inherited
+    pub extends: Option<String>,
+    //source structs of a dto declared with compose!, its map and ignore names are prefixed by the source
+    //such as "User.id"
+    pub sources: Vec<String>,
+    //span of the #[mapper(...)] attribute used to report validation errors
+    pub span: Option<Span>,
 }
 
 //DataStructure for the type of mapper values found in each entry
@@ -28,6 +71,21 @@ pub struct MapValue {
     pub to_field: Option<String>,
     pub macro_attr: Vec<String>,
     pub required: bool,
+    //attributes of the struct field to forward, it overrides forward_attrs of the mapper entry
+    pub forward_attrs: Option<Vec<String>>,
+    //cfg predicate of the dto field. The struct field may not exist when it is cfg-ed out
+    pub cfg: Option<String>,
+    //an Option<T> struct field is unwrapped into a T dto field
+    pub unwrap: bool,
+    //expression used when an unwrapped struct field is None
+    pub unwrap_or: Option<String>,
+    pub redact: bool,
+    //a struct field mapped several times is converted back from its primary tuple
+    pub primary: bool,
+    //path of the function converting the struct field into the dto field
+    pub convert: Option<String>,
+    //type of the dto field returned by the convert function, the struct field type by default
+    pub ty: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -37,6 +95,8 @@ pub struct NewField {
     //init_value is used compute this field value in the DTO during conversion with into()
     pub expression_value: String,
     pub attributes: Vec<String>,
+    //a redacted field is printed as <redacted> by the Debug implementation of the dto
+    pub redact: bool,
 }
 
 impl NewField {
@@ -46,12 +106,13 @@ impl NewField {
             field_type: r#type.to_string(),
             expression_value: init_expression.to_string(),
             attributes: attr.unwrap_or(vec![]),
+            redact: false,
         }
     }
 }
 
 impl MapValue {
-    fn new(map_tuple: &MapTuple) -> Self {
+    fn new(map_tuple: &MapTuple) -> syn::Result<Self> {
         let fields = map_tuple.0.as_str().split(":");
         let fields: Vec<&str> = fields.collect();
 
@@ -64,37 +125,344 @@ impl MapValue {
 
         let required = map_tuple.1;
 
-        Self {
+        let mut map_value = Self {
             from_field,
             to_field,
             required,
             macro_attr,
+            ..Self::default()
+        };
+        for option in map_tuple.3.iter() {
+            map_value.parse_option(option)?;
+        }
+        Ok(map_value)
+    }
+
+    //name of the dto field, which is the struct field name unless it is renamed
+    pub fn dto_name(&self) -> &str {
+        self.to_field.as_deref().unwrap_or(&self.from_field)
+    }
+
+    fn parse_option(&mut self, option: &MapOption) -> syn::Result<()> {
+        let (key, value, span) = option;
+        let key = key.to_lowercase();
+        match (key.as_str(), value) {
+            (FORWARD_ATTRS, Some(Expr::Array(expr_arr))) => {
+                self.forward_attrs = Some(MapperEntry::parse_array_of_string(expr_arr));
+            }
+            (CFG, Some(Expr::Lit(expr))) => {
+                self.cfg = Some(parse_cfg_predicate(expr)?);
+            }
+            (UNWRAP, None) => self.unwrap = true,
+            (REDACT, None) => self.redact = true,
+            (PRIMARY, None) => self.primary = true,
+            (
+                CONVERT,
+                Some(Expr::Lit(ExprLit {
+                    lit: Lit::Str(lit_str),
+                    ..
+                })),
+            ) => self.convert = Some(lit_str.value()),
+            (
+                TY,
+                Some(Expr::Lit(ExprLit {
+                    lit: Lit::Str(lit_str),
+                    ..
+                })),
+            ) => self.ty = Some(lit_str.value()),
+            (
+                UNWRAP_OR,
+                Some(Expr::Lit(ExprLit {
+                    lit: Lit::Str(lit_str),
+                    ..
+                })),
+            ) => {
+                self.unwrap = true;
+                self.unwrap_or = Some(lit_str.value());
+            }
+            (key, _) if MAP_OPTIONS.contains(&key) => {
+                return Err(syn::Error::new(
+                    *span,
+                    format!(
+                        "option `{}` of mapped field `{}` must be written as {}",
+                        key,
+                        self.from_field,
+                        map_option_syntax(key)
+                    ),
+                ))
+            }
+            (key, _) => {
+                return Err(syn::Error::new(
+                    *span,
+                    format!(
+                        "unknown option `{}` for mapped field `{}`{}",
+                        key,
+                        self.from_field,
+                        utils::did_you_mean(key, MAP_OPTIONS)
+                    ),
+                ))
+            }
         }
+        Ok(())
     }
 }
 
 const DTO: &'static str = "dto";
 const MAP: &'static str = "map";
 const IGNORE: &'static str = "ignore";
-//const ALL_FIELD: &'static str = "include_all";
+const ALL_FIELD: &str = "include_all";
 const DERIVE: &'static str = "derive";
 const WITHOUT_BUILDER: &'static str = "no_builder";
 const NEW_FIELDS: &'static str = "new_fields";
 const EXACTLY: &'static str = "exactly";
+const EXHAUSTIVE: &str = "exhaustive";
 const MACRO_ATTR: &'static str = "macro_attr";
+const SENSITIVE: &str = "sensitive";
+const ALLOW_SENSITIVE: &str = "allow_sensitive";
+const IGNORE_TYPES: &str = "ignore_types";
+const INCLUDE_TYPES: &str = "include_types";
+const INCLUDE_GROUPS: &str = "include_groups";
+const EXCLUDE_GROUPS: &str = "exclude_groups";
+const FORWARD_ATTRS: &str = "forward_attrs";
+const FORWARD_EXCLUDE: &str = "forward_exclude";
+const GETTERS: &str = "getters";
+const SETTERS: &str = "setters";
+const FIELD_VIS: &str = "field_vis";
+const DOC: &str = "doc";
+const CFG: &str = "cfg";
+const UNWRAP: &str = "unwrap";
+const UNWRAP_OR: &str = "unwrap_or";
+const REDACT: &str = "redact";
+const PRIMARY: &str = "primary";
+const CONVERT: &str = "convert";
+const TY: &str = "ty";
+const ZEROIZE: &str = "zeroize";
+const EXTENDS: &str = "extends";
+const CONVERT_TO: &str = "convert_to";
+const SOURCES: &str = "sources";
+const TARGET: &str = "target";
+const GENERATE: &str = "generate";
+pub const PRIVATE_VIS: &str = "private";
+
+//properties accepted by #[mapper(...)] on the struct
+const PROPERTIES: [&str; 29] = [
+    DTO,
+    TARGET,
+    GENERATE,
+    EXTENDS,
+    CONVERT_TO,
+    MAP,
+    IGNORE,
+    IGNORE_TYPES,
+    INCLUDE_TYPES,
+    INCLUDE_GROUPS,
+    EXCLUDE_GROUPS,
+    ALL_FIELD,
+    DERIVE,
+    WITHOUT_BUILDER,
+    NEW_FIELDS,
+    EXACTLY,
+    EXHAUSTIVE,
+    MACRO_ATTR,
+    SENSITIVE,
+    ALLOW_SENSITIVE,
+    REDACT,
+    ZEROIZE,
+    FORWARD_ATTRS,
+    FORWARD_EXCLUDE,
+    GETTERS,
+    SETTERS,
+    FIELD_VIS,
+    DOC,
+    CFG,
+];
+
+//properties which #[mapper_defaults(...)] can share with every mapper entry
+const DEFAULT_PROPERTIES: [&str; 10] = [
+    DERIVE,
+    MACRO_ATTR,
+    WITHOUT_BUILDER,
+    FIELD_VIS,
+    FORWARD_ATTRS,
+    FORWARD_EXCLUDE,
+    GETTERS,
+    SETTERS,
+    SENSITIVE,
+    EXHAUSTIVE,
+];
+
+//properties accepted by #[mapper(...)] inside compose!
+const COMPOSE_PROPERTIES: [&str; 13] = [
+    DTO,
+    SOURCES,
+    MAP,
+    IGNORE,
+    ALL_FIELD,
+    DERIVE,
+    WITHOUT_BUILDER,
+    MACRO_ATTR,
+    FORWARD_ATTRS,
+    FORWARD_EXCLUDE,
+    FIELD_VIS,
+    DOC,
+    ALLOW_SENSITIVE,
+];
+
+//options accepted after the required flag of a map tuple
+const MAP_OPTIONS: [&str; 8] = [
+    FORWARD_ATTRS,
+    CFG,
+    UNWRAP,
+    UNWRAP_OR,
+    REDACT,
+    PRIMARY,
+    CONVERT,
+    TY,
+];
+
+//how a map option is written, for an option given a value of the wrong kind
+fn map_option_syntax(key: &str) -> &str {
+    match key {
+        FORWARD_ATTRS => "forward_attrs=[\"serde\"]",
+        CFG => "cfg=\"feature = \\\"name\\\"\"",
+        UNWRAP_OR => "unwrap_or=\"expression\"",
+        CONVERT => "convert=\"path::to::function\"",
+        TY => "ty=\"Type\"",
+        UNWRAP => "a bare `unwrap` flag",
+        REDACT => "a bare `redact` flag",
+        _ => "a bare `primary` flag",
+    }
+}
 
 impl MapperEntry {
-    pub fn build(attr: &Attribute) -> syn::Result<Self> {
+    //properties of #[mapper_defaults(...)] are parsed first so that the entry extends or overrides them
+    pub fn build(attr: &Attribute, defaults: Option<&Attribute>) -> syn::Result<Self> {
+        let mut mapper_entry = MapperEntry {
+            span: Some(attr.span()),
+            generate: true,
+            ..MapperEntry::default()
+        };
+        //Mapper will always set a Default derive
+        mapper_entry.derive.push("Default".to_string());
+
+        if let Some(defaults_attr) = defaults {
+            let nested =
+                defaults_attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+            for meta in nested.iter() {
+                Self::check_property(meta, &DEFAULT_PROPERTIES, "#[mapper_defaults]")?;
+            }
+            Self::parse_properties(&mut mapper_entry, &nested)?;
+        }
+
         let nested = attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
         //println!("nested count={:?}",nested.iter().count());
+        for meta in nested.iter() {
+            Self::check_property(meta, &PROPERTIES, "#[mapper]")?;
+        }
 
-        let mut mapper_entry = MapperEntry::default();
-        //Mapper will always set a Default derive
+        //dto property is required unless the entry maps into a target type
+        let mut dto_prop = Self::parse_properties(&mut mapper_entry, &nested)?;
+        if let Some(ref target) = mapper_entry.target {
+            if dto_prop.is_some() {
+                return Err(syn::Error::new(
+                    attr.span(),
+                    "`dto` and `target` properties can't be used together, the target type is the dto",
+                ));
+            }
+            if mapper_entry.generate {
+                return Err(syn::Error::new(
+                    attr.span(),
+                    format!(
+                        "`target` type {} must already exist, add `generate = false` to only generate the conversions",
+                        target
+                    ),
+                ));
+            }
+            if syn::parse_str::<syn::Path>(target).is_err() {
+                return Err(syn::Error::new(
+                    attr.span(),
+                    format!(
+                        "`target` {} isn't a type path such as api::LoginPayload",
+                        target
+                    ),
+                ));
+            }
+            mapper_entry.dto = target.clone();
+            dto_prop = Some(target.clone());
+        }
+        if !mapper_entry.generate {
+            //the generated code of these properties implements traits or methods on the dto itself
+            let dto_impls = [
+                (GETTERS, mapper_entry.getters),
+                (SETTERS, mapper_entry.setters),
+                (REDACT, !mapper_entry.get_redacted_fields().is_empty()),
+                (ZEROIZE, !mapper_entry.zeroize.is_empty()),
+            ];
+            if let Some((property, _)) = dto_impls.iter().find(|(_, is_set)| *is_set) {
+                return Err(syn::Error::new(
+                    attr.span(),
+                    format!(
+                        "`{}` property can't be used with `generate = false` since the dto isn't generated",
+                        property
+                    ),
+                ));
+            }
+        }
+        Self::finish(mapper_entry, attr, dto_prop)
+    }
+
+    //the dto name without the path of a target type, such as LoginPayload for api::LoginPayload
+    pub fn dto_name(&self) -> &str {
+        self.dto.rsplit("::").next().unwrap_or(&self.dto).trim()
+    }
+
+    //a dto declared with compose! is built from the fields of several source structs
+    pub fn build_composed(attr: &Attribute) -> syn::Result<Self> {
+        let mut mapper_entry = MapperEntry {
+            span: Some(attr.span()),
+            ..MapperEntry::default()
+        };
         mapper_entry.derive.push("Default".to_string());
 
-        //dto property is required
+        let nested = attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+        for meta in nested.iter() {
+            Self::check_property(meta, &COMPOSE_PROPERTIES, "compose!")?;
+        }
+        let dto_prop = Self::parse_properties(&mut mapper_entry, &nested)?;
+        Self::finish(mapper_entry, attr, dto_prop)
+    }
+
+    fn finish(
+        mut mapper_entry: MapperEntry,
+        attr: &Attribute,
+        dto_prop: Option<String>,
+    ) -> syn::Result<Self> {
+        //the builder is derived once no_builder is known whatever the order of the properties
+        if !mapper_entry.no_builder {
+            mapper_entry.derive.insert(1, "Builder".into());
+        }
+
+        //dto property is required and must be checked
+        match dto_prop {
+            Some(val) if isblank(&val) => Err(syn::Error::new(
+                attr.span(),
+                "`dto` property is blank. It must not have whitespace",
+            )),
+            None => Err(syn::Error::new(
+                attr.span(),
+                "`dto` property is missing.It is required for mapper",
+            )),
+            _ => Ok(mapper_entry),
+        }
+    }
+
+    //parses the properties into the entry and returns the dto name when the dto property is set
+    fn parse_properties(
+        mapper_entry: &mut MapperEntry,
+        nested: &Punctuated<Meta, Token![,]>,
+    ) -> syn::Result<Option<String>> {
         let mut dto_prop: Option<String> = None;
-        nested.iter().for_each(|meta| {
+        for meta in nested.iter() {
             if let Meta::NameValue(metaname) = meta {
                 let ident = metaname.path.get_ident().unwrap();
                 let keyname = utils::remove_white_space(&ident.to_string());
@@ -104,16 +472,52 @@ impl MapperEntry {
                 if let Expr::Lit(expr) = &metaname.value {
                     if keyname.eq_ignore_ascii_case(DTO) {
                         //we should read the string value
-                        Self::parse_dto_attribute(&mut mapper_entry, &expr);
+                        Self::parse_dto_attribute(mapper_entry, expr);
                         dto_prop = Some(mapper_entry.dto.to_string());
                     }
 
                     //
+                    if keyname.eq_ignore_ascii_case(TARGET) {
+                        if let Lit::Str(lit_str) = &expr.lit {
+                            mapper_entry.target = Some(utils::remove_white_space(&lit_str.value()));
+                        }
+                    }
+                    if keyname.eq_ignore_ascii_case(GENERATE) {
+                        if let Lit::Bool(lit_bool) = &expr.lit {
+                            mapper_entry.generate = lit_bool.value();
+                        }
+                    }
                     if keyname.eq_ignore_ascii_case(WITHOUT_BUILDER) {
-                        Self::parse_no_builder_attribute(&mut mapper_entry, &expr);
+                        Self::parse_no_builder_attribute(mapper_entry, &expr);
                     }
                     if keyname.eq_ignore_ascii_case(EXACTLY) {
-                        Self::parse_exactly_attribute(&mut mapper_entry, &expr);
+                        Self::parse_exactly_attribute(mapper_entry, &expr);
+                    }
+                    if keyname.eq_ignore_ascii_case(ALL_FIELD) {
+                        Self::parse_include_all_attribute(mapper_entry, &expr);
+                    }
+                    if keyname.eq_ignore_ascii_case(EXHAUSTIVE) {
+                        Self::parse_exhaustive_attribute(mapper_entry, &expr);
+                    }
+                    if keyname.eq_ignore_ascii_case(GETTERS) {
+                        Self::parse_getters_attribute(mapper_entry, &expr);
+                    }
+                    if keyname.eq_ignore_ascii_case(SETTERS) {
+                        Self::parse_setters_attribute(mapper_entry, &expr);
+                    }
+                    if keyname.eq_ignore_ascii_case(FIELD_VIS) {
+                        Self::parse_field_vis_attribute(mapper_entry, &expr)?;
+                    }
+                    if keyname.eq_ignore_ascii_case(DOC) {
+                        Self::parse_doc_attribute(mapper_entry, &expr);
+                    }
+                    if keyname.eq_ignore_ascii_case(CFG) {
+                        mapper_entry.cfg = Some(parse_cfg_predicate(expr)?);
+                    }
+                    if keyname.eq_ignore_ascii_case(EXTENDS) {
+                        if let Lit::Str(lit_str) = &expr.lit {
+                            mapper_entry.extends = Some(lit_str.value().trim().to_string());
+                        }
                     }
                 }
 
@@ -121,43 +525,262 @@ impl MapperEntry {
                     //println!("{} array has {} elements",keyname,expr_arr.elems.iter().clone().count());
                     if keyname.eq_ignore_ascii_case(MAP) {
                         //map is a vec of tuples such as map=[("f1",true),("f2",false)]
-                        Self::parse_map_attribute(&mut mapper_entry, expr_arr);
+                        Self::parse_map_attribute(mapper_entry, expr_arr)?;
                     }
 
                     if keyname.eq_ignore_ascii_case(NEW_FIELDS) {
-                        Self::parse_new_fields_attribute(&mut mapper_entry, expr_arr);
+                        Self::parse_new_fields_attribute(mapper_entry, expr_arr)?;
                     }
                     if keyname.eq_ignore_ascii_case(&MACRO_ATTR) {
-                        Self::parse_macro_attr_attribute(&mut mapper_entry, expr_arr);
+                        Self::parse_macro_attr_attribute(mapper_entry, expr_arr)?;
                     }
 
                     if keyname.eq_ignore_ascii_case(IGNORE) {
                         //ignore is a vec of string such as ignore=["val1","val2"]
-                        Self::parse_ignore_attribute(&mut mapper_entry, expr_arr);
+                        Self::parse_ignore_attribute(mapper_entry, expr_arr)?;
                     }
-                }
 
-                if let Expr::Tuple(tuple_expr) = &metaname.value {
-                    //println!("keyname {} is Tuple of literal value",keyname);
-                    if keyname.eq_ignore_ascii_case(DERIVE) {
-                        Self::parse_derive_attribute(&mut mapper_entry, tuple_expr);
+                    if keyname.eq_ignore_ascii_case(SENSITIVE) {
+                        mapper_entry
+                            .sensitive
+                            .extend(Self::parse_array_of_string(expr_arr));
+                    }
+                    if keyname.eq_ignore_ascii_case(IGNORE_TYPES) {
+                        mapper_entry.ignore_types = Self::parse_array_of_string(expr_arr);
+                    }
+                    if keyname.eq_ignore_ascii_case(INCLUDE_TYPES) {
+                        mapper_entry.include_types = Self::parse_array_of_string(expr_arr);
+                    }
+                    if keyname.eq_ignore_ascii_case(INCLUDE_GROUPS) {
+                        mapper_entry.include_groups = Self::parse_array_of_string(expr_arr);
+                    }
+                    if keyname.eq_ignore_ascii_case(EXCLUDE_GROUPS) {
+                        mapper_entry.exclude_groups = Self::parse_array_of_string(expr_arr);
+                    }
+                    if keyname.eq_ignore_ascii_case(ALLOW_SENSITIVE) {
+                        mapper_entry.allow_sensitive = Self::parse_array_of_string(expr_arr);
+                    }
+                    if keyname.eq_ignore_ascii_case(ZEROIZE) {
+                        if !cfg!(feature = "zeroize") {
+                            return Err(syn::Error::new(
+                                metaname.path.span(),
+                                format!(
+                                    "`{}` property requires the `zeroize` feature, enable it with dto_mapper = {{ features = [\"zeroize\"] }} in Cargo.toml",
+                                    ZEROIZE
+                                ),
+                            ));
+                        }
+                        mapper_entry.zeroize = Self::parse_array_of_string(expr_arr);
+                    }
+                    if keyname.eq_ignore_ascii_case(SOURCES) {
+                        mapper_entry.sources = Self::parse_array_of_string(expr_arr);
+                    }
+                    if keyname.eq_ignore_ascii_case(CONVERT_TO) {
+                        mapper_entry.convert_to = Self::parse_array_of_string(expr_arr);
+                    }
+                    if keyname.eq_ignore_ascii_case(REDACT) {
+                        mapper_entry.redact = Self::parse_array_of_string(expr_arr);
+                    }
+                    if keyname.eq_ignore_ascii_case(FORWARD_ATTRS) {
+                        mapper_entry
+                            .forward_attrs
+                            .extend(Self::parse_array_of_string(expr_arr));
+                    }
+                    if keyname.eq_ignore_ascii_case(FORWARD_EXCLUDE) {
+                        mapper_entry
+                            .forward_exclude
+                            .extend(Self::parse_array_of_string(expr_arr));
                     }
                 }
+
+                if keyname.eq_ignore_ascii_case(DERIVE) {
+                    Self::parse_derive_attribute(mapper_entry, &metaname.value)?;
+                }
             }
-        });
+        }
+        Ok(dto_prop)
+    }
 
-        //dto property is required and must be checked
-        match dto_prop {
-            Some(val) if isblank(&val) => Err(syn::Error::new(
-                attr.span(),
-                "`dto` property is blank. It must not have whitespace",
-            )),
-            None => Err(syn::Error::new(
-                attr.span(),
-                "`dto` property is missing.It is required for mapper",
-            )),
-            _ => Ok(mapper_entry),
+    //properties are written as key=value and unknown keys are rejected instead of being ignored
+    fn check_property(meta: &Meta, properties: &[&str], scope: &str) -> syn::Result<()> {
+        let Meta::NameValue(metaname) = meta else {
+            return Err(syn::Error::new(
+                meta.span(),
+                "mapper properties must be written as `key = value`",
+            ));
+        };
+        let Some(ident) = metaname.path.get_ident() else {
+            return Err(syn::Error::new(
+                metaname.path.span(),
+                "mapper property name must be an identifier",
+            ));
+        };
+        let keyname = ident.to_string().to_lowercase();
+        if properties.contains(&keyname.as_str()) {
+            return Ok(());
+        }
+        if PROPERTIES.contains(&keyname.as_str()) {
+            return Err(syn::Error::new(
+                ident.span(),
+                format!("`{}` property can't be used in {}", ident, scope),
+            ));
+        }
+        Err(syn::Error::new(
+            ident.span(),
+            format!(
+                "unknown mapper property `{}`{}",
+                ident,
+                utils::did_you_mean(&keyname, properties.iter().copied())
+            ),
+        ))
+    }
+
+    //a profile entry is shared by many structs, so the names it selects which are missing
+    //from a struct are dropped instead of being reported
+    pub fn retain_struct_fields(&mut self, st_entry: &StructEntry) {
+        let had_exclusions = !self.ignore.is_empty()
+            || !self.ignore_types.is_empty()
+            || !self.exclude_groups.is_empty();
+        let has_field = |pattern: &String| {
+            st_entry
+                .field_entries
+                .iter()
+                .any(|field| utils::glob_match(pattern, &field.field_name))
+        };
+        let has_type = |pattern: &String| {
+            st_entry
+                .field_entries
+                .iter()
+                .any(|field| field.matches_type(pattern))
+        };
+        let has_group = |group: &String| {
+            st_entry
+                .field_entries
+                .iter()
+                .any(|field| field.groups.contains(group))
+        };
+
+        self.ignore.retain(has_field);
+        self.map
+            .retain(|m_value| m_value.cfg.is_some() || has_field(&m_value.from_field));
+        self.ignore_types.retain(has_type);
+        self.include_types.retain(has_type);
+        self.include_groups.retain(has_group);
+        self.exclude_groups.retain(has_group);
+        //an entry excluding fields keeps taking every other field even if none of its exclusions remain
+        if had_exclusions {
+            self.include_all = true;
         }
+
+        let mut dto_fields: Vec<String> = get_dto_fields(st_entry, self)
+            .into_iter()
+            .map(|dto_field| dto_field.name)
+            .collect();
+        self.new_fields
+            .iter()
+            .for_each(|new_field| dto_fields.push(new_field.field_name.to_string()));
+        self.redact.retain(|name| dto_fields.contains(name));
+        self.zeroize.retain(|name| dto_fields.contains(name));
+    }
+
+    //an entry extending another one starts from the fields of the extended dto, with their renames and options.
+    //Its own map tuples are added or override the inherited ones, and its ignore lists remove inherited fields
+    pub fn extend_from(&mut self, parent: &MapperEntry, st_entry: &StructEntry) {
+        let ignore_fields = get_ignore_fields(st_entry, self);
+        let own_map = get_map_of_mapvalue(st_entry, self);
+        let is_inherited = |field_name: &String| {
+            !ignore_fields.contains(field_name) && !own_map.contains_key(field_name)
+        };
+
+        let mut map: Vec<MapValue> = get_dto_fields(st_entry, parent)
+            .into_iter()
+            .filter(|dto_field| is_inherited(&dto_field.source.field_name))
+            .map(|dto_field| {
+                dto_field.map_value.unwrap_or(MapValue {
+                    from_field: dto_field.source.field_name.to_string(),
+                    required: true,
+                    ..MapValue::default()
+                })
+            })
+            .collect();
+        //fields mapped under a cfg predicate may be cfg-ed out of the struct
+        let cfg_map: Vec<MapValue> = parent
+            .map
+            .iter()
+            .filter(|m_value| m_value.cfg.is_some() && is_inherited(&m_value.from_field))
+            .filter(|m_value| !map.iter().any(|m| m.from_field == m_value.from_field))
+            .cloned()
+            .collect();
+        map.extend(cfg_map);
+        map.append(&mut self.map);
+        self.map = map;
+        //inherited fields are already selected by map, the exclusions only applied to them
+        if !self.include_all {
+            self.ignore.clear();
+            self.ignore_types.clear();
+            self.exclude_groups.clear();
+        }
+
+        let mut new_fields: Vec<NewField> = parent
+            .new_fields
+            .iter()
+            .filter(|new_field| {
+                !self
+                    .new_fields
+                    .iter()
+                    .any(|own_field| own_field.field_name == new_field.field_name)
+            })
+            .cloned()
+            .collect();
+        new_fields.append(&mut self.new_fields);
+        self.new_fields = new_fields;
+
+        //the builder of the extended dto isn't inherited since it depends on no_builder
+        let inherited_derives: Vec<String> = parent
+            .derive
+            .iter()
+            .filter(|derive| derive.as_str() != "Builder" && !self.derive.contains(derive))
+            .cloned()
+            .collect();
+        self.derive.extend(inherited_derives);
+        for (list, parent_list) in [
+            (&mut self.macro_attr, &parent.macro_attr),
+            (&mut self.forward_attrs, &parent.forward_attrs),
+            (&mut self.forward_exclude, &parent.forward_exclude),
+            (&mut self.sensitive, &parent.sensitive),
+            (&mut self.allow_sensitive, &parent.allow_sensitive),
+            (&mut self.redact, &parent.redact),
+            (&mut self.zeroize, &parent.zeroize),
+        ] {
+            parent_list.iter().for_each(|value| {
+                if !list.contains(value) {
+                    list.push(value.clone());
+                }
+            });
+        }
+        self.getters |= parent.getters;
+        self.setters |= parent.setters;
+        if self.field_vis.is_none() {
+            self.field_vis = parent.field_vis.clone();
+        }
+        if self.cfg.is_none() {
+            self.cfg = parent.cfg.clone();
+        }
+    }
+
+    //redacted dto fields are listed in redact or flagged as redact in map and new_fields
+    pub fn get_redacted_fields(&self) -> Vec<String> {
+        let mut redacted: Vec<String> = self.redact.clone();
+        self.map
+            .iter()
+            .filter(|m_value| m_value.redact)
+            .map(|m_value| m_value.dto_name().to_string())
+            .for_each(|name| redacted.push(name));
+        self.new_fields
+            .iter()
+            .filter(|new_field| new_field.redact)
+            .for_each(|new_field| redacted.push(new_field.field_name.clone()));
+        redacted
     }
 
     fn parse_no_builder_attribute(mapper_entry: &mut MapperEntry, expr: &&ExprLit) {
@@ -172,31 +795,102 @@ impl MapperEntry {
         }
     }
 
-    fn parse_derive_attribute(mapper_entry: &mut MapperEntry, tuple_expr: &ExprTuple) {
-        let derive_items = tuple_expr
-            .elems
-            .iter()
-            .map(|elem_expr| {
-                if let Expr::Path(path_exp) = &elem_expr {
-                    let ident = path_exp.path.get_ident().unwrap();
-                    let derive_obj: String = ident.to_string();
-                    derive_obj
-                } else {
-                    "".to_string()
-                }
-            })
-            .collect::<Vec<String>>();
+    fn parse_include_all_attribute(mapper_entry: &mut MapperEntry, expr: &&ExprLit) {
+        if let Lit::Bool(lit_bool) = &expr.lit {
+            mapper_entry.include_all = lit_bool.value();
+        }
+    }
 
-        //Adding a builder by default if property isn't explicitly set to true
-        if !mapper_entry.no_builder {
-            mapper_entry.derive.push("Builder".into());
+    fn parse_exhaustive_attribute(mapper_entry: &mut MapperEntry, expr: &&ExprLit) {
+        if let Lit::Bool(lit_bool) = &expr.lit {
+            mapper_entry.exhaustive = lit_bool.value();
         }
+    }
 
+    fn parse_getters_attribute(mapper_entry: &mut MapperEntry, expr: &&ExprLit) {
+        if let Lit::Bool(lit_bool) = &expr.lit {
+            mapper_entry.getters = lit_bool.value();
+        }
+    }
+
+    fn parse_setters_attribute(mapper_entry: &mut MapperEntry, expr: &&ExprLit) {
+        if let Lit::Bool(lit_bool) = &expr.lit {
+            mapper_entry.setters = lit_bool.value();
+        }
+    }
+
+    fn parse_field_vis_attribute(
+        mapper_entry: &mut MapperEntry,
+        expr: &&ExprLit,
+    ) -> syn::Result<()> {
+        if let Lit::Str(lit_str) = &expr.lit {
+            let vis = lit_str.value().trim().to_string();
+            //"private" keeps the fields private to the module where the dto is generated
+            if !vis.eq(PRIVATE_VIS) && syn::parse_str::<syn::Visibility>(&vis).is_err() {
+                return Err(syn::Error::new(
+                    lit_str.span(),
+                    format!(
+                        "`{}` attribute has an invalid visibility: {}, expected such as \"pub(crate)\" or \"private\"",
+                        FIELD_VIS, vis
+                    ),
+                ));
+            }
+            mapper_entry.field_vis = Some(vis);
+        }
+        Ok(())
+    }
+
+    fn parse_doc_attribute(mapper_entry: &mut MapperEntry, expr: &&ExprLit) {
+        if let Lit::Str(lit_str) = &expr.lit {
+            mapper_entry.doc = Some(lit_str.value());
+        }
+    }
+
+    //derive is a tuple of trait names such as derive=(Debug, Clone), or a single one such as derive=(Debug)
+    fn parse_derive_attribute(mapper_entry: &mut MapperEntry, expr: &Expr) -> syn::Result<()> {
+        let elems: Vec<&Expr> = match expr {
+            Expr::Tuple(tuple_expr) => tuple_expr.elems.iter().collect(),
+            Expr::Paren(paren_expr) => vec![paren_expr.expr.as_ref()],
+            Expr::Path(_) => vec![expr],
+            _ => {
+                return Err(syn::Error::new(
+                    expr.span(),
+                    format!(
+                        "`{}` must be a tuple of traits such as derive=(Debug, Clone)",
+                        DERIVE
+                    ),
+                ))
+            }
+        };
+        let mut derive_items: Vec<String> = Vec::new();
+        for elem in elems {
+            let ident = match elem {
+                Expr::Path(path_exp) => path_exp.path.get_ident(),
+                _ => None,
+            };
+            let Some(ident) = ident else {
+                return Err(syn::Error::new(
+                    elem.span(),
+                    format!(
+                        "`{}` items must be trait names in scope such as Debug",
+                        DERIVE
+                    ),
+                ));
+            };
+            derive_items.push(ident.to_string());
+        }
+
+        //the derives of an entry replace those of #[mapper_defaults], Default is always derived
+        mapper_entry.derive.retain(|val| val.eq("Default"));
         derive_items
-            .iter()
-            .filter(|&val| !val.eq("Default"))
-            .map(|val| val.clone())
-            .for_each(|val| mapper_entry.derive.push(val));
+            .into_iter()
+            .filter(|val| !val.eq("Default") && !val.eq("Builder"))
+            .for_each(|val| {
+                if !mapper_entry.derive.contains(&val) {
+                    mapper_entry.derive.push(val);
+                }
+            });
+        Ok(())
     }
 
     fn parse_dto_attribute(mapper_entry: &mut MapperEntry, expr: &ExprLit) {
@@ -206,47 +900,76 @@ impl MapperEntry {
         }
     }
 
-    fn parse_ignore_attribute(mapper_entry: &mut MapperEntry, expr_arr: &ExprArray) {
+    fn parse_ignore_attribute(
+        mapper_entry: &mut MapperEntry,
+        expr_arr: &ExprArray,
+    ) -> syn::Result<()> {
         let ignore_arr = Self::parse_array_of_string(expr_arr);
         //println!("{}={:?}",keyname, ignore_arr);
         //check if matt attribute is blank
         if ignore_arr.iter().filter(|&text| isblank(text)).count() > 0 {
-            panic!("`{}` attribute must not be blank", IGNORE);
+            return Err(syn::Error::new(
+                expr_arr.span(),
+                format!("`{}` attribute must not be blank", IGNORE),
+            ));
         };
         mapper_entry.ignore = ignore_arr;
+        Ok(())
     }
 
-    fn parse_new_fields_attribute(mapper_entry: &mut MapperEntry, expr_arr: &ExprArray) {
-        mapper_entry.new_fields = Self::parse_array_of_new_fields(expr_arr);
+    fn parse_new_fields_attribute(
+        mapper_entry: &mut MapperEntry,
+        expr_arr: &ExprArray,
+    ) -> syn::Result<()> {
+        mapper_entry.new_fields = Self::parse_array_of_new_fields(expr_arr)?;
         //println!("{:?}",mapper_entry.new_fields);
         if mapper_entry.new_fields.len() == 0 {
-            panic!(
-                "`{}` attribute must not be empty or have odd number of elements",
-                NEW_FIELDS
-            );
+            return Err(syn::Error::new(
+                expr_arr.span(),
+                format!(
+                    "`{}` attribute must not be empty or have odd number of elements",
+                    NEW_FIELDS
+                ),
+            ));
         };
+        Ok(())
     }
 
-    fn parse_macro_attr_attribute(mapper_entry: &mut MapperEntry, expr_arr: &ExprArray) {
+    fn parse_macro_attr_attribute(
+        mapper_entry: &mut MapperEntry,
+        expr_arr: &ExprArray,
+    ) -> syn::Result<()> {
         if mapper_entry
             .macro_attr
             .iter()
             .find(|attr| isblank(attr))
             .is_some()
         {
-            panic!(
-                "`{}` attribute must not be empty. Remove it if it's not needed. {:?}",
-                MACRO_ATTR, mapper_entry.macro_attr
-            );
+            return Err(syn::Error::new(
+                expr_arr.span(),
+                format!(
+                    "`{}` attribute must not be empty. Remove it if it's not needed. {:?}",
+                    MACRO_ATTR, mapper_entry.macro_attr
+                ),
+            ));
         }
-        mapper_entry.macro_attr = Self::parse_array_of_macro_attr(expr_arr);
+        mapper_entry
+            .macro_attr
+            .extend(Self::parse_array_of_macro_attr(expr_arr));
         //println!("{:?}",mapper_entry.new_fields);
+        Ok(())
     }
 
-    fn parse_map_attribute(mapper_entry: &mut MapperEntry, expr_arr: &ExprArray) {
+    fn parse_map_attribute(
+        mapper_entry: &mut MapperEntry,
+        expr_arr: &ExprArray,
+    ) -> syn::Result<()> {
         let map_tuples = Self::parse_array_of_tuple(expr_arr);
         //println!("{}={:?}",keyname,map_tuple);
-        mapper_entry.map = map_tuples.iter().map(MapValue::new).collect();
+        mapper_entry.map = map_tuples
+            .iter()
+            .map(MapValue::new)
+            .collect::<syn::Result<Vec<MapValue>>>()?;
         if mapper_entry
             .map
             .iter()
@@ -254,8 +977,12 @@ impl MapperEntry {
             .count()
             > 0
         {
-            panic!("`{}` attribute must not be blank", MAP);
+            return Err(syn::Error::new(
+                expr_arr.span(),
+                format!("`{}` attribute must not be blank", MAP),
+            ));
         };
+        Ok(())
     }
 
     fn parse_array_of_macro_attr(expr_arr: &ExprArray) -> Vec<String> {
@@ -280,7 +1007,7 @@ impl MapperEntry {
     }
 
     fn parse_array_of_tuple(expr_arr: &ExprArray) -> Vec<MapTuple> {
-        let mut vec_tuple: Vec<(String, bool, Vec<String>)> = Vec::new();
+        let mut vec_tuple: Vec<MapTuple> = Vec::new();
 
         for elem in expr_arr.elems.iter() {
             if let Expr::Tuple(el_exp) = elem {
@@ -288,6 +1015,7 @@ impl MapperEntry {
                 let mut str_val: Option<String> = None;
                 let mut flag: Option<bool> = None;
                 let mut attrs: Vec<String> = Vec::new();
+                let mut options: Vec<MapOption> = Vec::new();
                 for content_expr in el_exp.elems.iter() {
                     if let Expr::Lit(content_lit) = content_expr {
                         if let Lit::Str(content) = &content_lit.lit {
@@ -306,10 +1034,24 @@ impl MapperEntry {
                     if let Expr::Array(content_arr) = content_expr {
                         attrs = Self::parse_array_of_macro_attr(&content_arr);
                     }
+
+                    //options are either key=value or a bare flag
+                    if let Expr::Assign(assign) = content_expr {
+                        if let Expr::Path(key) = assign.left.as_ref() {
+                            options.push((
+                                quote::quote!(#key).to_string(),
+                                Some(assign.right.as_ref().clone()),
+                                key.span(),
+                            ));
+                        }
+                    }
+                    if let Expr::Path(key) = content_expr {
+                        options.push((quote::quote!(#key).to_string(), None, key.span()));
+                    }
                 }
 
                 if str_val.is_some() && flag.is_some() {
-                    let tuple: MapTuple = (str_val.unwrap(), flag.unwrap(), attrs);
+                    let tuple: MapTuple = (str_val.unwrap(), flag.unwrap(), attrs, options);
                     vec_tuple.push(tuple);
                 }
                 //println!("");
@@ -319,20 +1061,21 @@ impl MapperEntry {
         vec_tuple
     }
 
-    fn parse_array_of_new_fields(expr_arr: &ExprArray) -> Vec<NewField> {
+    fn parse_array_of_new_fields(expr_arr: &ExprArray) -> syn::Result<Vec<NewField>> {
         let mut vec_tuple: Vec<NewField> = Vec::new();
 
         for elem in expr_arr.elems.iter() {
-            Self::process_new_fields(&mut vec_tuple, elem);
+            Self::process_new_fields(&mut vec_tuple, elem)?;
         }
 
-        vec_tuple
+        Ok(vec_tuple)
     }
 
-    fn process_new_fields(mut vec_tuple: &mut Vec<NewField>, elem: &Expr) {
+    fn process_new_fields(mut vec_tuple: &mut Vec<NewField>, elem: &Expr) -> syn::Result<()> {
         if let Expr::Tuple(el_exp) = elem {
             let mut field_data: [Option<String>; 2] = [None, None];
             let mut attributes: Vec<String> = Vec::new();
+            let mut redact = false;
             let total_passed_args = el_exp.elems.len();
 
             // eprintln!("===========>");
@@ -357,9 +1100,22 @@ impl MapperEntry {
                             }
                         }
                     }
-                    2 => {
-                        // Attributes array
-                        attributes = extract_attributes(content_expr);
+                    2 | 3 => {
+                        // Attributes array or redact flag
+                        if let Expr::Path(flag) = content_expr {
+                            if !flag.path.is_ident(REDACT) {
+                                return Err(syn::Error::new(
+                                    flag.span(),
+                                    format!(
+                                        "unknown flag for new field, only `{}` is allowed",
+                                        REDACT
+                                    ),
+                                ));
+                            }
+                            redact = true;
+                        } else {
+                            attributes = extract_attributes(content_expr);
+                        }
                         // eprintln!("attributes={:#?}", attributes);
                     }
                     _ => unreachable!(),
@@ -383,19 +1139,28 @@ impl MapperEntry {
                                 field_decl,
                                 &colon_position,
                                 new_field_attr,
-                            );
+                            )
+                            .map_err(|message| syn::Error::new(el_exp.span(), message))?;
+                            if let Some(new_field) = vec_tuple.last_mut() {
+                                new_field.redact = redact;
+                            }
                         } else {
-                            panic!("Missing `:` character for field declaration");
+                            return Err(syn::Error::new(
+                                el_exp.span(),
+                                "Missing `:` character for field declaration",
+                            ));
                         }
                     }
                     // Reset for next field
                     field_data = [None, None];
                     attributes.clear();
+                    redact = false;
                 }
             }
 
             // eprintln!("===========>");
         }
+        Ok(())
     }
 
     fn insert_next_field_value(
@@ -404,12 +1169,14 @@ impl MapperEntry {
         field_decl: &String,
         colon_position: &usize,
         attributes: Option<Vec<String>>,
-    ) {
+    ) -> Result<(), &'static str> {
         if *colon_position == 0 {
-            panic!("`:` cannot be the first character. Need to specify new fieldname for struct");
+            return Err(
+                "`:` cannot be the first character. Need to specify new fieldname for struct",
+            );
         }
         if *colon_position == field_decl.len() - 1 {
-            panic!("Need to specify a type for the fieldname after `:`");
+            return Err("Need to specify a type for the fieldname after `:`");
         }
 
         let field_name = &field_decl.as_str()[..*colon_position];
@@ -421,6 +1188,7 @@ impl MapperEntry {
             str_val.as_str(),
             attributes,
         ));
+        Ok(())
     }
 
     fn parse_array_of_string(expr_arr: &ExprArray) -> Vec<String> {
@@ -438,6 +1206,30 @@ impl MapperEntry {
     }
 }
 
+//a cfg predicate is a string such as cfg="feature = \"billing\"" which must be a valid cfg(...) content
+fn parse_cfg_predicate(expr: &ExprLit) -> syn::Result<String> {
+    if let Lit::Str(lit_str) = &expr.lit {
+        let predicate = lit_str.value();
+        if let Err(error) = syn::parse_str::<Meta>(&predicate) {
+            return Err(syn::Error::new(
+                lit_str.span(),
+                format!(
+                    "`{}` predicate \"{}\" is not valid: {}",
+                    CFG, predicate, error
+                ),
+            ));
+        }
+        return Ok(predicate);
+    }
+    Err(syn::Error::new(
+        expr.span(),
+        format!(
+            "`{}` must be a string such as cfg=\"feature = \\\"name\\\"\"",
+            CFG
+        ),
+    ))
+}
+
 // Helper to extract extra attrs
 fn extract_attributes(expr: &Expr) -> Vec<String> {
     if let Expr::Array(array_expr) = expr {
@@ -460,3 +1252,118 @@ fn extract_attributes(expr: &Expr) -> Vec<String> {
         Vec::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn test_property_typo_suggests_the_property() {
+        let attr: Attribute = parse_quote! {
+            #[mapper(dto = "UserDto", ignor = ["email"])]
+        };
+        let error = MapperEntry::build(&attr, None).unwrap_err();
+        assert_eq!(
+            "unknown mapper property `ignor`, did you mean `ignore`?",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_map_option_typo_suggests_the_option() {
+        let attr: Attribute = parse_quote! {
+            #[mapper(dto = "UserDto", map = [("age", true, unwrp)])]
+        };
+        let error = MapperEntry::build(&attr, None).unwrap_err();
+        assert_eq!(
+            "unknown option `unwrp` for mapped field `age`, did you mean `unwrap`?",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_map_option_with_a_value_of_the_wrong_kind() {
+        let attr: Attribute = parse_quote! {
+            #[mapper(dto = "UserDto", map = [("age", true, unwrap_or = 5)])]
+        };
+        let error = MapperEntry::build(&attr, None).unwrap_err();
+        assert_eq!(
+            r#"option `unwrap_or` of mapped field `age` must be written as unwrap_or="expression""#,
+            error.to_string()
+        );
+        let attr: Attribute = parse_quote! {
+            #[mapper(dto = "UserDto", map = [("age", true, forward_attrs = "serde")])]
+        };
+        let error = MapperEntry::build(&attr, None).unwrap_err();
+        assert_eq!(
+            r#"option `forward_attrs` of mapped field `age` must be written as forward_attrs=["serde"]"#,
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_invalid_cfg_predicate() {
+        let attr: Attribute = parse_quote! {
+            #[mapper(dto = "UserDto", map = [("age", true)], cfg = "feature = ")]
+        };
+        let error = MapperEntry::build(&attr, None).unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .starts_with(r#"`cfg` predicate "feature = " is not valid: "#),
+            "{}",
+            error
+        );
+        let attr: Attribute = parse_quote! {
+            #[mapper(dto = "UserDto", map = [("age", true, cfg = 5)])]
+        };
+        let error = MapperEntry::build(&attr, None).unwrap_err();
+        assert_eq!(
+            r#"`cfg` must be a string such as cfg="feature = \"name\"""#,
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_unknown_new_field_flag() {
+        let attr: Attribute = parse_quote! {
+            #[mapper(dto = "UserDto", map = [("age", true)], new_fields = [("nick: String", "String::new()", redcat)])]
+        };
+        let error = MapperEntry::build(&attr, None).unwrap_err();
+        assert_eq!(
+            "unknown flag for new field, only `redact` is allowed",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_blank_ignored_field() {
+        let attr: Attribute = parse_quote! {
+            #[mapper(dto = "UserDto", ignore = [" "])]
+        };
+        let error = MapperEntry::build(&attr, None).unwrap_err();
+        assert_eq!("`ignore` attribute must not be blank", error.to_string());
+    }
+
+    #[test]
+    fn test_new_field_without_type() {
+        let attr: Attribute = parse_quote! {
+            #[mapper(dto = "UserDto", map = [("age", true)], new_fields = [("nick", "String::new()")])]
+        };
+        let error = MapperEntry::build(&attr, None).unwrap_err();
+        assert_eq!(
+            "Missing `:` character for field declaration",
+            error.to_string()
+        );
+
+        let attr: Attribute = parse_quote! {
+            #[mapper(dto = "UserDto", map = [("age", true)], new_fields = [("nick:", "String::new()")])]
+        };
+        let error = MapperEntry::build(&attr, None).unwrap_err();
+        assert_eq!(
+            "Need to specify a type for the fieldname after `:`",
+            error.to_string()
+        );
+    }
+}
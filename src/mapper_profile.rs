@@ -0,0 +1,153 @@
+use std::{fs, path::PathBuf};
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{
+    punctuated::Punctuated, spanned::Spanned, Attribute, Expr, ExprLit, Item, Lit, Meta, Token,
+};
+
+use crate::utils::did_you_mean;
+
+const PROFILE: &str = "profile";
+const PROFILE_FILE: &str = "profile_file";
+//profiles file looked up in the directory of the crate using dto_mapper
+const DEFAULT_PROFILE_FILE: &str = "dto_profiles.rs";
+//replaced by the name of the struct in the profiles file
+const STRUCT_TEMPLATE: &str = "{Struct}";
+const MAPPER: &str = "mapper";
+
+//a mapper attribute such as #[mapper(profile = "crud")] refers to a profile instead of declaring a dto.
+//Several profiles of the same file are referenced with #[mapper(profile = ["crud", "summary"])]
+pub struct ProfileRef {
+    pub names: Vec<String>,
+    pub path: PathBuf,
+    pub span: Span,
+}
+
+impl ProfileRef {
+    //returns None when the attribute isn't a profile reference
+    pub fn build(attr: &Attribute) -> syn::Result<Option<Self>> {
+        let nested = attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+        if !nested.iter().any(|meta| meta.path().is_ident(PROFILE)) {
+            return Ok(None);
+        }
+
+        let mut names: Vec<String> = Vec::new();
+        let mut file = DEFAULT_PROFILE_FILE.to_string();
+        for meta in nested.iter() {
+            let Meta::NameValue(metaname) = meta else {
+                return Err(syn::Error::new(
+                    meta.span(),
+                    "profile properties must be written as `key = value`",
+                ));
+            };
+            let values: Vec<&Expr> = match &metaname.value {
+                Expr::Array(expr_arr) if meta.path().is_ident(PROFILE) => {
+                    expr_arr.elems.iter().collect()
+                }
+                value => vec![value],
+            };
+            let mut values = values
+                .into_iter()
+                .map(|value| match value {
+                    Expr::Lit(ExprLit {
+                        lit: Lit::Str(lit_str),
+                        ..
+                    }) => Ok(lit_str.value()),
+                    _ => Err(syn::Error::new(
+                        value.span(),
+                        "profile properties must be string literals",
+                    )),
+                })
+                .collect::<syn::Result<Vec<String>>>()?;
+            if meta.path().is_ident(PROFILE) {
+                names.append(&mut values);
+            } else if meta.path().is_ident(PROFILE_FILE) {
+                file = values.remove(0);
+            } else {
+                return Err(syn::Error::new(
+                    meta.path().span(),
+                    format!(
+                        "a mapper profile reference only accepts `{}` and `{}`",
+                        PROFILE, PROFILE_FILE
+                    ),
+                ));
+            }
+        }
+
+        //relative paths are resolved against the directory of the crate being compiled
+        let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap_or_default();
+        Ok(Some(Self {
+            names,
+            path: PathBuf::from(manifest_dir).join(file),
+            span: attr.span(),
+        }))
+    }
+
+    //profiles are unit structs of the profiles file holding #[mapper(...)] attributes such as
+    //#[mapper(dto = "{Struct}Response", ignore = ["password_hash"])] struct crud;
+    pub fn load_attributes(&self, struct_name: &str) -> syn::Result<Vec<Attribute>> {
+        let content = fs::read_to_string(&self.path).map_err(|error| {
+            syn::Error::new(
+                self.span,
+                format!(
+                    "failed reading mapper profiles from {} : {}",
+                    self.path.display(),
+                    error
+                ),
+            )
+        })?;
+        let file =
+            syn::parse_file(&content.replace(STRUCT_TEMPLATE, struct_name)).map_err(|error| {
+                syn::Error::new(
+                    self.span,
+                    format!(
+                        "failed parsing mapper profiles from {} : {}",
+                        self.path.display(),
+                        error
+                    ),
+                )
+            })?;
+
+        let profiles: Vec<(String, Vec<Attribute>)> = file
+            .items
+            .into_iter()
+            .filter_map(|item| match item {
+                Item::Struct(item_struct) => {
+                    Some((item_struct.ident.to_string(), item_struct.attrs))
+                }
+                _ => None,
+            })
+            .collect();
+
+        let mut attributes: Vec<Attribute> = Vec::new();
+        for profile_name in self.names.iter() {
+            let Some((_, attrs)) = profiles.iter().find(|(name, _)| name == profile_name) else {
+                return Err(syn::Error::new(
+                    self.span,
+                    format!(
+                        "mapper profile `{}` not found in {}{}",
+                        profile_name,
+                        self.path.display(),
+                        did_you_mean(profile_name, profiles.iter().map(|(name, _)| name.as_str()))
+                    ),
+                ));
+            };
+            attributes.extend(
+                attrs
+                    .iter()
+                    .filter(|attr| attr.path().is_ident(MAPPER))
+                    .cloned(),
+            );
+        }
+        Ok(attributes)
+    }
+
+    //the struct is compiled again whenever the profiles file changes
+    pub fn track_file(&self) -> TokenStream {
+        let path = self.path.to_string_lossy().to_string();
+        quote! {
+            const _: &[u8] = include_bytes!(#path);
+        }
+    }
+}
@@ -1,11 +1,17 @@
-use syn::Type;
+use syn::{punctuated::Punctuated, spanned::Spanned, Attribute, Field, LitStr, Meta, Token, Type};
 use syn::{Data, DataStruct, DeriveInput, Fields};
 
+use quote::{format_ident, ToTokens};
+
+use crate::utils::{did_you_mean, glob_match, remove_white_space};
+
 //A StructEntry will hold the structure name and a list(vector) of FieldEntry
 #[derive(Default)]
 pub struct StructEntry {
     pub name: String,
     pub field_entries: Vec<FieldEntry>,
+    //path of the struct of another crate mirrored with remote!
+    pub remote: Option<syn::Path>,
 }
 
 //FieldEntry will hold data about a field from a struct such that its name, and its type
@@ -14,6 +20,14 @@ pub struct FieldEntry {
     pub field_name: String,
     pub field_type: Type,
     pub is_optional: bool,
+    //T when the field type is an Option<T>
+    pub inner_type: Option<Type>,
+    //a sensitive field can only be mapped to a dto which explicitly allows it
+    pub is_sensitive: bool,
+    //groups such as "public" or "admin" the field belongs to, selected by include_groups and exclude_groups
+    pub groups: Vec<String>,
+    //attributes declared on the field such as doc comments
+    pub attributes: Vec<Attribute>,
 }
 
 impl StructEntry {
@@ -29,56 +43,183 @@ impl StructEntry {
             unimplemented!("Implemented only for structure not other type")
         };
 
-        let struct_entries: Vec<FieldEntry> = fields
-            .iter()
-            .map(|field| {
-                //let name = format!("{:?}",field.ident);
-                let name = field.clone().ident.unwrap().to_string();
+        let mut struct_entries: Vec<FieldEntry> = Vec::new();
+        for field in fields.iter() {
+            //let name = format!("{:?}",field.ident);
+            let name = field.clone().ident.unwrap().to_string();
 
-                let has_option = is_type_option(&field.ty);
-                //println!("field={} is_optional={}",name,has_option);
+            let has_option = is_type_option(&field.ty);
+            //println!("field={} is_optional={}",name,has_option);
 
-                FieldEntry {
-                    field_name: name,
-                    field_type: field.ty.clone(),
-                    is_optional: has_option,
-                }
-            })
-            .collect();
+            let mut field_entry = FieldEntry {
+                field_name: name,
+                field_type: field.ty.clone(),
+                is_optional: has_option,
+                inner_type: ty_inner_type("Option", &field.ty).cloned(),
+                is_sensitive: false,
+                groups: Vec::new(),
+                attributes: field.attrs.clone(),
+            };
+            field_entry.parse_mapper_attributes(field)?;
+            struct_entries.push(field_entry);
+        }
         //let struct_entry = StructEntry::default();
         syn::Result::Ok(Self {
             name: struct_name,
             field_entries: struct_entries,
+            remote: None,
         })
     }
+
+    //the struct type used by the conversions
+    pub fn path(&self) -> syn::Path {
+        match self.remote {
+            Some(ref remote) => remote.clone(),
+            None => format_ident!("{}", self.name.as_str()).into(),
+        }
+    }
 }
 
+const MAPPER: &str = "mapper";
+const OPTIONAL: &str = "optional";
+const NOT_OPTIONAL: &str = "not_optional";
+const SENSITIVE: &str = "sensitive";
+const GROUPS: &str = "groups";
+const FIELD_ATTRIBUTES: [&str; 4] = [OPTIONAL, NOT_OPTIONAL, SENSITIVE, GROUPS];
+
+impl FieldEntry {
+    //fields can be annotated with #[mapper(...)] such as #[mapper(optional)] for a type alias of an Option
+    //or #[mapper(groups("public", "admin"))]
+    fn parse_mapper_attributes(&mut self, field: &Field) -> syn::Result<()> {
+        for attr in field.attrs.iter().filter(|a| a.path().is_ident(MAPPER)) {
+            let nested = attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+            for meta in nested.iter() {
+                match meta {
+                    Meta::Path(path) if path.is_ident(OPTIONAL) => self.is_optional = true,
+                    Meta::Path(path) if path.is_ident(NOT_OPTIONAL) => self.is_optional = false,
+                    Meta::Path(path) if path.is_ident(SENSITIVE) => self.is_sensitive = true,
+                    Meta::List(list) if list.path.is_ident(GROUPS) => {
+                        let groups = list
+                            .parse_args_with(Punctuated::<LitStr, Token![,]>::parse_terminated)?;
+                        self.groups.extend(groups.iter().map(|group| group.value()));
+                    }
+                    _ => {
+                        return Err(syn::Error::new(
+                            meta.span(),
+                            format!(
+                                "unknown mapper attribute on field `{}`{}",
+                                self.field_name,
+                                did_you_mean(
+                                    &meta
+                                        .path()
+                                        .get_ident()
+                                        .map(|ident| ident.to_string())
+                                        .unwrap_or_default(),
+                                    FIELD_ATTRIBUTES
+                                )
+                            ),
+                        ))
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    //type selectors such as "Secret<_>" use `_` as a wildcard and also match a type written with its path
+    //such as secrecy::Secret<String>
+    pub fn matches_type(&self, pattern: &str) -> bool {
+        let field_type = compact_type(&self.field_type.to_token_stream().to_string());
+        let pattern = match syn::parse_str::<Type>(pattern) {
+            Ok(pattern_type) => compact_type(&pattern_type.to_token_stream().to_string()),
+            Err(_) => remove_white_space(&pattern.to_string()),
+        };
+        glob_match(&pattern, &field_type) || glob_match(&format!("*::{}", pattern), &field_type)
+    }
+
+    //doc comments are held by #[doc = "..."] attributes
+    pub fn doc_attributes(&self) -> Vec<&Attribute> {
+        self.attributes
+            .iter()
+            .filter(|attr| attr.path().is_ident("doc"))
+            .collect()
+    }
+
+    //#[cfg(...)] attributes are replicated wherever the field is used by generated code
+    pub fn cfg_attributes(&self) -> Vec<&Attribute> {
+        self.attributes
+            .iter()
+            .filter(|attr| attr.path().is_ident("cfg"))
+            .collect()
+    }
+
+    //an Option<T> is a Copy type when T is a Copy type
+    pub fn is_copy(&self) -> bool {
+        is_type_copy(&self.field_type) || self.inner_type.as_ref().is_some_and(is_type_copy)
+    }
+
+    pub fn cfg_attr_attributes(&self) -> Vec<&Attribute> {
+        self.attributes
+            .iter()
+            .filter(|attr| attr.path().is_ident("cfg_attr"))
+            .collect()
+    }
+}
+
+const OPTION_PATHS: [&str; 3] = ["Option", "std::option::Option", "core::option::Option"];
+
 //https://github.com/jonhoo/proc-macro-workshop/blob/master/builder/src/lib.rs
 //this code snippet is inspired from the builder workshop for syn library
-//This is to figure out if type is an option
+//This is to figure out if type is an option, including fully qualified paths such as std::option::Option
 pub fn is_type_option(a_type: &Type) -> bool {
     if let Type::Path(ref p) = a_type {
-        if p.path.segments.len() > 0 && p.path.segments[0].ident == "Option" {
-            return true;
-        }
+        let path = p
+            .path
+            .segments
+            .iter()
+            .map(|segment| segment.ident.to_string())
+            .collect::<Vec<String>>()
+            .join("::");
 
-        return false;
+        return p.qself.is_none() && OPTION_PATHS.contains(&path.as_str());
     }
     //return false by default
     false
 }
 
-fn _ty_inner_type<'a>(wrapper: &str, ty: &'a syn::Type) -> Option<&'a syn::Type> {
+const COPY_TYPES: [&str; 16] = [
+    "bool", "char", "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64", "u128",
+    "usize", "f32", "f64",
+];
+
+//A macro can't tell if a type implements Copy. We only consider primitives and shared references
+//as Copy types so that getters can return them by value.
+pub fn is_type_copy(a_type: &Type) -> bool {
+    match a_type {
+        Type::Path(p) => {
+            p.qself.is_none()
+                && p.path.get_ident().is_some()
+                && COPY_TYPES.contains(&p.path.segments[0].ident.to_string().as_str())
+        }
+        Type::Reference(r) => r.mutability.is_none(),
+        Type::Tuple(t) => t.elems.is_empty(),
+        _ => false,
+    }
+}
+
+//this returns T for a type such as Option<T> where Option is the wrapper
+pub fn ty_inner_type<'a>(wrapper: &str, ty: &'a syn::Type) -> Option<&'a syn::Type> {
+    if wrapper == "Option" && !is_type_option(ty) {
+        return None;
+    }
+
     if let syn::Type::Path(ref p) = ty {
-        //println!("segment len: {}",p.path.segments.len());
-        /*if p.path.segments.len()>0 {
-            println!("Segment Ident={}", p.path.segments[0].ident);
-        }*/
-        if p.path.segments.len() != 1 || p.path.segments[0].ident != wrapper {
+        let segment = p.path.segments.last()?;
+        if segment.ident != wrapper {
             return None;
         }
 
-        if let syn::PathArguments::AngleBracketed(ref inner_ty) = p.path.segments[0].arguments {
+        if let syn::PathArguments::AngleBracketed(ref inner_ty) = segment.arguments {
             if inner_ty.args.len() != 1 {
                 return None;
             }
@@ -92,3 +233,11 @@ fn _ty_inner_type<'a>(wrapper: &str, ty: &'a syn::Type) -> Option<&'a syn::Type>
     }
     None
 }
+
+//type tokens joined without whitespace where the `_` placeholder becomes a glob wildcard
+fn compact_type(tokens: &str) -> String {
+    tokens
+        .split_whitespace()
+        .map(|token| if token == "_" { "*" } else { token })
+        .collect()
+}
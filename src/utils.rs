@@ -8,3 +8,127 @@ pub fn remove_white_space(str: &String) -> String {
 pub fn isblank(str: &String) -> bool {
     remove_white_space(str).is_empty()
 }
+
+//converts a type name such as LoginDto into login_dto
+pub fn to_snake_case(name: &str) -> String {
+    let mut snake = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            snake.push('_');
+        }
+        snake.extend(c.to_lowercase());
+    }
+    snake
+}
+
+//a name containing `*` or `?` is a glob pattern
+pub fn is_glob(text: &str) -> bool {
+    text.contains(['*', '?'])
+}
+
+//matches a text against a glob pattern where `*` matches any sequence of characters
+//and `?` matches any single character. For example `*password*` matches `password_hash`
+pub fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut p, mut t) = (0, 0);
+    //position of the last `*` in the pattern and the position in the text it started matching from
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == text[t]) {
+            p += 1;
+            t += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            backtrack = Some((p, t));
+            p += 1;
+        } else if let Some((star, start)) = backtrack {
+            p = star + 1;
+            t = start + 1;
+            backtrack = Some((star, start + 1));
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+//number of single character insertions, deletions or substitutions turning a text into another one
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current: Vec<usize> = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + if ca == *cb { 0 } else { 1 };
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+//closest candidate to a misspelled name, if it is close enough to be a typo
+pub fn closest_match<'a>(
+    name: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Option<&'a str> {
+    let max_distance = name.chars().count().max(3) / 3;
+    candidates
+        .into_iter()
+        .map(|candidate| (edit_distance(name, candidate), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+//", did you mean `x`?" suffix for error messages, or an empty string without a close candidate
+pub fn did_you_mean<'a>(name: &str, candidates: impl IntoIterator<Item = &'a str>) -> String {
+    match closest_match(name, candidates) {
+        Some(candidate) => format!(", did you mean `{}`?", candidate),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("*password*", "password_hash"));
+        assert!(glob_match("*password*", "old_password"));
+        assert!(glob_match("internal_*", "internal_"));
+        assert!(glob_match("code_?", "code_a"));
+        assert!(!glob_match("code_?", "code_ab"));
+        assert!(!glob_match("internal_*", "name"));
+        assert!(!glob_match("*_hash", "hash_salt"));
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(0, edit_distance("email", "email"));
+        assert_eq!(1, edit_distance("emal", "email"));
+        assert_eq!(2, edit_distance("emial", "email"));
+        assert_eq!(5, edit_distance("", "email"));
+    }
+
+    #[test]
+    fn test_did_you_mean() {
+        let fields = ["name", "email", "password"];
+        assert_eq!(Some("email"), closest_match("emal", fields));
+        assert_eq!(
+            ", did you mean `password`?",
+            did_you_mean("pasword", fields)
+        );
+        assert_eq!("", did_you_mean("address", fields));
+    }
+
+    #[test]
+    fn test_is_glob() {
+        assert!(is_glob("internal_*"));
+        assert!(is_glob("code_?"));
+        assert!(!is_glob("email"));
+    }
+}
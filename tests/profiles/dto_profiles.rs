@@ -0,0 +1,9 @@
+// mapper profiles shared by the structs of the profile tests
+
+#[mapper(dto = "{Struct}Response", ignore = ["password_hash", "deleted_at"], derive = (Debug, Clone, PartialEq))]
+#[mapper(dto = "{Struct}Create", ignore = ["id", "created_at"], derive = (Debug, Clone, PartialEq))]
+#[mapper(dto = "{Struct}Update", ignore = ["id"], map = [("*", false)], derive = (Debug, Clone, PartialEq))]
+struct crud;
+
+#[mapper(dto = "{Struct}Summary", map = [("id", true), ("name", true), ("title", true)], derive = (Debug, Clone, PartialEq))]
+struct summary;
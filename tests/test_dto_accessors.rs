@@ -0,0 +1,45 @@
+#[cfg(test)]
+mod test_dto_accessors {
+    use dto_mapper::DtoMapper;
+
+    #[derive(DtoMapper, Debug, Default, Clone)]
+    #[mapper( dto="LoginView" , no_builder=true , field_vis="private" , getters=true , map=[ ("username:login",true) , ("age",true)] )]
+    #[mapper( dto="ProfileForm" , no_builder=true , getters=true , setters=true , ignore=["password"] ,
+        new_fields=[( "password_length: usize", "self.password.len()" )]
+    )]
+    struct User {
+        username: String,
+        password: String,
+        email: String,
+        age: u8,
+    }
+
+    #[test]
+    fn test_getters_on_private_fields() {
+        let user = User {
+            username: "dessalines".into(),
+            age: 50,
+            ..User::default()
+        };
+        let login_view: LoginView = user.into();
+
+        let login: &String = login_view.login();
+        let age: u8 = login_view.age();
+        assert_eq!("dessalines", login);
+        assert_eq!(50, age);
+    }
+
+    #[test]
+    fn test_setters_and_with_methods() {
+        let mut profile_form = ProfileForm::default()
+            .with_username("toussaint")
+            .with_age(60u8);
+        profile_form.set_email("toussaint@mail.ht");
+        profile_form.set_password_length(8usize);
+
+        assert_eq!("toussaint", profile_form.username());
+        assert_eq!("toussaint@mail.ht", profile_form.email());
+        assert_eq!(8, profile_form.password_length());
+        assert_eq!(60, profile_form.age());
+    }
+}
@@ -0,0 +1,43 @@
+#[cfg(test)]
+mod test_dto_cfg {
+    use dto_mapper::DtoMapper;
+
+    //any() is never satisfied while not(any()) always is
+    #[derive(DtoMapper, Debug, Default, Clone)]
+    #[mapper( dto="AccountDto" , no_builder=true , getters=true ,
+        map=[ ("name",true) , ("plan",true, cfg="any()") , ("seats",false) ]
+    )]
+    #[mapper( dto="BillingDto" , no_builder=true , cfg="any()" , map=[ ("plan",true, cfg="any()") ] )]
+    #[mapper( dto="SeatsDto" , no_builder=true , cfg="not(any())" , ignore=["name"] )]
+    struct Account {
+        name: String,
+        #[cfg(any())]
+        plan: String,
+        #[cfg(not(any()))]
+        seats: u32,
+    }
+
+    #[test]
+    fn test_cfg_fields_are_replicated_on_dto_and_conversions() {
+        let account = Account {
+            name: "Ayiti".into(),
+            seats: 3,
+        };
+        let account_dto: AccountDto = account.into();
+        assert_eq!("Ayiti", account_dto.name());
+        assert_eq!(Some(3), account_dto.seats());
+
+        let account: Account = account_dto.into();
+        assert_eq!(3, account.seats);
+    }
+
+    #[test]
+    fn test_cfg_on_mapper_entry() {
+        let seats_dto: SeatsDto = Account {
+            seats: 5,
+            ..Account::default()
+        }
+        .into();
+        assert_eq!(5, seats_dto.seats);
+    }
+}
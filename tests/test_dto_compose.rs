@@ -0,0 +1,116 @@
+#[cfg(test)]
+mod test_dto_compose {
+    use derive_builder::Builder;
+    use dto_mapper::DtoMapper;
+
+    #[derive(DtoMapper, Debug, Default, Clone, PartialEq)]
+    #[mapper(composable)]
+    #[mapper( dto="UserDto" , no_builder=true , map=[ ("name",true) ] )]
+    struct User {
+        id: u32,
+        name: String,
+        email: Option<String>,
+        #[mapper(sensitive)]
+        token: String,
+    }
+
+    mod billing {
+        use dto_mapper::DtoMapper;
+
+        #[derive(DtoMapper, Debug, Default, Clone, PartialEq)]
+        #[mapper(composable)]
+        #[mapper( dto="AccountDto" , no_builder=true , map=[ ("balance",true) ] )]
+        pub struct Account {
+            pub id: u32,
+            pub balance: i64,
+            pub iban: String,
+        }
+    }
+    use billing::Account;
+
+    dto_mapper::compose!(
+        #[mapper( dto="UserAccountDto" , sources=["User", "billing::Account"] , no_builder=true ,
+            derive=(Debug, PartialEq) ,
+            map=[ ("User.id:user_id",true) , ("User.name",true) , ("User.email",true, unwrap_or="String::new()") ,
+                ("Account.id:account_id",true) , ("Account.balance",true) ]
+        )]
+        #[mapper( dto="UserIbanDto" , sources=["User", "Account"] , derive=(Debug, PartialEq) ,
+            map=[ ("User.name",true) , ("Account.iban",true) ]
+        )]
+        #[mapper( dto="UserTokenDto" , sources=["User", "Account"] , no_builder=true , allow_sensitive=["User.token"] ,
+            map=[ ("User.token",true) , ("Account.id",true) ]
+        )]
+    );
+
+    fn sources() -> (User, Account) {
+        (
+            User {
+                id: 1,
+                name: "dessalines".into(),
+                email: Some("dessalines@mail.ht".into()),
+                token: "x1804".into(),
+            },
+            Account {
+                id: 7,
+                balance: 1804,
+                iban: "HT1804".into(),
+            },
+        )
+    }
+
+    #[test]
+    fn test_compose_from_sources() {
+        let dto: UserAccountDto = sources().into();
+        assert_eq!(
+            UserAccountDto {
+                user_id: 1,
+                name: "dessalines".into(),
+                email: "dessalines@mail.ht".into(),
+                account_id: 7,
+                balance: 1804,
+            },
+            dto
+        );
+
+        let iban_dto: UserIbanDto = sources().into();
+        assert_eq!("dessalines", iban_dto.name);
+        assert_eq!("HT1804", iban_dto.iban);
+        let iban_dto = UserIbanDtoBuilder::default()
+            .name("toussaint".into())
+            .build()
+            .unwrap();
+        assert_eq!("", iban_dto.iban);
+    }
+
+    #[test]
+    fn test_compose_split_into_sources() {
+        let dto: UserAccountDto = sources().into();
+        let (user, account): (User, Account) = dto.into();
+        assert_eq!(
+            User {
+                token: String::new(),
+                ..sources().0
+            },
+            user
+        );
+        assert_eq!(
+            Account {
+                iban: String::new(),
+                ..sources().1
+            },
+            account
+        );
+    }
+
+    #[test]
+    fn test_compose_allowed_sensitive_field() {
+        let (mut user, account) = sources();
+        user.email = None;
+        let token_dto: UserTokenDto = (user.clone(), account).into();
+        assert_eq!("x1804", token_dto.token);
+        assert_eq!(7, token_dto.id);
+
+        let dto: UserAccountDto = (user, sources().1).into();
+        assert_eq!("", dto.email);
+    }
+}
@@ -0,0 +1,82 @@
+#[cfg(test)]
+mod test_dto_convert {
+    use dto_mapper::DtoMapper;
+
+    #[derive(DtoMapper, Debug, Default, Clone)]
+    #[mapper( dto="ProfileDto" , no_builder=true , derive=(Debug, PartialEq) ,
+        convert_to=["PersonDto", "ContactDto"] ,
+        map=[ ("username:login",true) , ("email",true) , ("age",true) , ("phone",true, unwrap) ] ,
+        new_fields=[( "display_name: String", "String::from(\"Duvalier\")" )]
+    )]
+    #[mapper( dto="PersonDto" , no_builder=true , derive=(Debug, PartialEq) ,
+        map=[ ("username:name",true) , ("age",false) ] ,
+        new_fields=[( "display_name: String", "String::from(\"Toussaint\")" )]
+    )]
+    #[mapper( dto="ContactDto" , no_builder=true , derive=(Debug, PartialEq) , convert_to=["PhoneDto"] ,
+        map=[ ("email",false) , ("phone",true) ]
+    )]
+    #[mapper( dto="PhoneDto" , no_builder=true , derive=(Debug, PartialEq) ,
+        map=[ ("phone",true, unwrap_or="String::from(\"unlisted\")") ]
+    )]
+    struct User {
+        username: String,
+        email: String,
+        age: u8,
+        phone: Option<String>,
+    }
+
+    fn profile_dto() -> ProfileDto {
+        User {
+            username: "dessalines".into(),
+            email: "dessalines@mail.ht".into(),
+            age: 46,
+            phone: Some("1804".into()),
+        }
+        .try_into()
+        .unwrap()
+    }
+
+    #[test]
+    fn test_convert_to_renamed_and_wrapped_fields() {
+        let person_dto: PersonDto = profile_dto().into();
+        assert_eq!(
+            PersonDto {
+                name: "dessalines".into(),
+                age: Some(46),
+                display_name: "Duvalier".into(),
+            },
+            person_dto
+        );
+    }
+
+    #[test]
+    fn test_convert_to_unwrapped_fields() {
+        let contact_dto: ContactDto = profile_dto().into();
+        assert_eq!(
+            ContactDto {
+                email: Some("dessalines@mail.ht".into()),
+                phone: Some("1804".into()),
+            },
+            contact_dto
+        );
+    }
+
+    #[test]
+    fn test_convert_to_unwrapped_fields_with_fallback() {
+        let contact_dto = ContactDto {
+            email: None,
+            phone: None,
+        };
+        let phone_dto: PhoneDto = contact_dto.into();
+        assert_eq!(
+            PhoneDto {
+                phone: "unlisted".into(),
+            },
+            phone_dto
+        );
+
+        let contact_dto: ContactDto = profile_dto().into();
+        let phone_dto: PhoneDto = contact_dto.into();
+        assert_eq!("1804", phone_dto.phone);
+    }
+}
@@ -0,0 +1,68 @@
+#[cfg(test)]
+mod test_dto_defaults {
+    use derive_builder::Builder;
+    use dto_mapper::DtoMapper;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(DtoMapper, Debug, Default, Clone)]
+    #[mapper_defaults( derive=(Debug, Clone, Serialize, Deserialize) , no_builder=true ,
+        macro_attr=["serde(rename_all = \"camelCase\")"] , getters=true
+    )]
+    #[mapper( dto="ProfileDto" , map=[ ("first_name",true) , ("last_name",true) ] )]
+    #[mapper( dto="ContactDto" , derive=(PartialEq, Debug, Serialize) , no_builder=false , getters=false ,
+        map=[ ("first_name",true) , ("email_address",true) ]
+    )]
+    #[mapper( dto="MaskedDto" , derive=(Serialize) , redact=["email_address"] , map=[ ("email_address",true) ] )]
+    struct User {
+        first_name: String,
+        last_name: String,
+        email_address: String,
+    }
+
+    fn user() -> User {
+        User {
+            first_name: "Catherine".into(),
+            last_name: "Flon".into(),
+            email_address: "flon@mail.ht".into(),
+        }
+    }
+
+    #[test]
+    fn test_entry_inherits_defaults() {
+        let profile_dto: ProfileDto = user().into();
+        assert_eq!("Catherine", profile_dto.first_name());
+        assert_eq!(
+            r#"{"firstName":"Catherine","lastName":"Flon"}"#,
+            serde_json::to_string(&profile_dto).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_entry_replaces_default_derives() {
+        //MaskedDto doesn't derive Debug from the defaults so that its fields can be redacted
+        let masked_dto: MaskedDto = user().into();
+        assert_eq!(
+            r#"MaskedDto { email_address: <redacted> }"#,
+            format!("{:?}", masked_dto)
+        );
+        assert_eq!(
+            r#"{"emailAddress":"flon@mail.ht"}"#,
+            serde_json::to_string(&masked_dto).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_entry_extends_and_overrides_defaults() {
+        let contact_dto: ContactDto = user().into();
+        let built_dto = ContactDtoBuilder::default()
+            .first_name("Catherine".to_string())
+            .email_address("flon@mail.ht".to_string())
+            .build()
+            .unwrap();
+        assert_eq!(built_dto, contact_dto);
+        assert_eq!(
+            r#"{"firstName":"Catherine","emailAddress":"flon@mail.ht"}"#,
+            serde_json::to_string(&contact_dto).unwrap()
+        );
+    }
+}
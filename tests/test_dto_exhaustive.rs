@@ -0,0 +1,32 @@
+#[cfg(test)]
+mod test_dto_exhaustive {
+    use dto_mapper::DtoMapper;
+
+    //adding a field to User without listing it in map or ignore fails to compile
+    #[derive(DtoMapper, Debug, Default, Clone)]
+    #[mapper( dto="ProfileDto" , no_builder=true , exhaustive=true , ignore=["password", "api_token"] , map=[ ("username",true) ] )]
+    #[mapper( dto="LoginDto" , no_builder=true , exhaustive=true , map=[ ("username",true), ("password",true) ] , ignore=["api_token"] )]
+    #[mapper( dto="AccountDto" , no_builder=true , exhaustive=true , include_all=true , map=[ ("username",true) ] , ignore=["password", "api_token"] )]
+    struct User {
+        username: String,
+        password: String,
+        api_token: String,
+    }
+
+    #[test]
+    fn test_exhaustive_dto() {
+        let user = User {
+            username: "dessalines".into(),
+            password: "hello123".into(),
+            api_token: "XXXXXXXX".into(),
+        };
+        assert_eq!(8, user.api_token.len());
+        let profile_dto: ProfileDto = user.clone().into();
+        let account_dto: AccountDto = user.clone().into();
+        let login_dto: LoginDto = user.into();
+
+        assert_eq!("dessalines", profile_dto.username);
+        assert_eq!("dessalines", account_dto.username);
+        assert_eq!("hello123", login_dto.password);
+    }
+}
@@ -0,0 +1,74 @@
+#[cfg(test)]
+mod test_dto_extends {
+    use dto_mapper::DtoMapper;
+
+    #[derive(DtoMapper, Debug, Default, Clone)]
+    #[mapper( dto="ProfileDto" , no_builder=true , derive=(Debug, Clone, PartialEq) ,
+        map=[ ("username:login",true) , ("email",false) ] ,
+        new_fields=[( "display_name: String", "String::from(\"Duvalier\")" )]
+    )]
+    #[mapper( dto="AdminProfileDto" , no_builder=true , extends="ProfileDto" , map=[ ("password_hash",true) ] )]
+    #[mapper( dto="PublicProfileDto" , no_builder=true , extends="ProfileDto" , ignore=["email"] , derive=(Debug, PartialEq, Eq) )]
+    struct User {
+        username: String,
+        email: String,
+        password_hash: String,
+        age: u8,
+    }
+
+    fn user() -> User {
+        User {
+            username: "dessalines".into(),
+            email: "dessalines@mail.ht".into(),
+            password_hash: "x1804".into(),
+            age: 46,
+        }
+    }
+
+    #[test]
+    fn test_extended_dto_adds_fields() {
+        let admin_dto: AdminProfileDto = user().into();
+        assert_eq!(
+            AdminProfileDto {
+                login: "dessalines".into(),
+                email: Some("dessalines@mail.ht".into()),
+                password_hash: "x1804".into(),
+                display_name: "Duvalier".into(),
+            },
+            admin_dto
+        );
+
+        let profile_dto: ProfileDto = admin_dto.into();
+        assert_eq!(
+            ProfileDto {
+                login: "dessalines".into(),
+                email: Some("dessalines@mail.ht".into()),
+                display_name: "Duvalier".into(),
+            },
+            profile_dto
+        );
+    }
+
+    fn assert_eq_impl<T: Eq>(_: &T) {}
+
+    #[test]
+    fn test_extended_dto_removes_fields() {
+        let public_dto: PublicProfileDto = user().into();
+        assert_eq_impl(&public_dto);
+        assert_eq!(
+            PublicProfileDto {
+                login: "dessalines".into(),
+                display_name: "Duvalier".into(),
+            },
+            public_dto
+        );
+
+        //the fields missing from the extending dto get their default value
+        let profile_dto: ProfileDto = public_dto.into();
+        assert_eq!(None, profile_dto.email);
+
+        let user: User = profile_dto.into();
+        assert_eq!("dessalines", user.username);
+        assert_eq!(0, user.age);
+    }
+}
@@ -0,0 +1,82 @@
+#[cfg(test)]
+mod test_dto_fanout {
+    use dto_mapper::DtoMapper;
+
+    fn domain_of(email: String) -> String {
+        email.split('@').nth(1).unwrap_or_default().to_string()
+    }
+
+    fn to_cents(price: f64) -> u64 {
+        (price * 100.0).round() as u64
+    }
+
+    #[derive(DtoMapper, Debug, Default, Clone, PartialEq)]
+    #[mapper( dto="ContactDto" , no_builder=true , derive=(Debug, Clone, PartialEq) ,
+        map=[ ("name",true) , ("email:primary_email",true, primary) , ("email:contact_email",false) ,
+            ("email:email_domain",true, convert="domain_of") ]
+    )]
+    #[mapper( dto="PriceDto" , no_builder=true , derive=(Debug, Clone, PartialEq) , convert_to=["CentsDto"] ,
+        map=[ ("price",true, primary) , ("price:cents",true, convert="to_cents", ty="u64") ]
+    )]
+    #[mapper( dto="CentsDto" , no_builder=true , derive=(Debug, Clone, PartialEq) ,
+        map=[ ("price:cents",true, convert="to_cents", ty="u64") ]
+    )]
+    struct Customer {
+        name: String,
+        email: String,
+        price: f64,
+    }
+
+    fn customer() -> Customer {
+        Customer {
+            name: "Anacaona".into(),
+            email: "anacaona@xaragua.ht".into(),
+            price: 12.5,
+        }
+    }
+
+    #[test]
+    fn test_struct_into_fanout_dto() {
+        let contact: ContactDto = customer().into();
+        assert_eq!(
+            ContactDto {
+                name: "Anacaona".into(),
+                primary_email: "anacaona@xaragua.ht".into(),
+                contact_email: Some("anacaona@xaragua.ht".into()),
+                email_domain: "xaragua.ht".into(),
+            },
+            contact
+        );
+
+        let price: PriceDto = customer().into();
+        assert_eq!(12.5, price.price);
+        assert_eq!(1250, price.cents);
+
+        let cents: CentsDto = price.into();
+        assert_eq!(CentsDto { cents: 1250 }, cents);
+    }
+
+    #[test]
+    fn test_fanout_dto_into_struct() {
+        //only the primary field is converted back, the cloned and converted fields are dropped
+        let contact = ContactDto {
+            name: "Henri".into(),
+            primary_email: "henri@sanssouci.ht".into(),
+            contact_email: Some("other@mail.ht".into()),
+            email_domain: "mail.ht".into(),
+        };
+        let customer: Customer = contact.into();
+        assert_eq!(
+            Customer {
+                name: "Henri".into(),
+                email: "henri@sanssouci.ht".into(),
+                price: 0.0,
+            },
+            customer
+        );
+
+        let cents = CentsDto { cents: 1250 };
+        let customer: Customer = cents.into();
+        assert_eq!(Customer::default(), customer);
+    }
+}
@@ -0,0 +1,60 @@
+#[cfg(test)]
+mod test_dto_forward_attrs {
+    use dto_mapper::DtoMapper;
+    use serde::{Deserialize, Serialize};
+    use validator::Validate;
+
+    #[derive(DtoMapper, Debug, Default, Clone, Serialize, Deserialize, Validate)]
+    #[mapper( dto="ContactDto" , no_builder=true , forward_attrs=["serde", "validate"] ,
+        map=[ ("username:login",true) , ("email",true) ] ,
+        derive=(Debug, Serialize, Validate)
+    )]
+    #[mapper( dto="RawContactDto" , no_builder=true , forward_attrs=["serde", "validate"] , forward_exclude=["email"] ,
+        map=[ ("username",true, forward_attrs=["validate"]) , ("email",true) ] ,
+        derive=(Debug, Serialize, Validate)
+    )]
+    struct User {
+        #[serde(rename = "user_name")]
+        #[validate(length(min = 3))]
+        username: String,
+        #[serde(rename = "mail")]
+        #[validate(email)]
+        email: String,
+    }
+
+    #[test]
+    fn test_forwarded_attributes_follow_renamed_fields() {
+        let user = User {
+            username: "jj".into(),
+            email: "dessalines@mail.ht".into(),
+        };
+        let contact_dto: ContactDto = user.into();
+
+        let json_string = serde_json::to_string(&contact_dto).unwrap();
+        assert_eq!(
+            json_string,
+            r#"{"user_name":"jj","mail":"dessalines@mail.ht"}"#
+        );
+
+        let validation_errors = contact_dto.validate().unwrap_err();
+        assert!(validation_errors.0.contains_key("login"));
+        assert!(!validation_errors.0.contains_key("email"));
+    }
+
+    #[test]
+    fn test_forwarded_attributes_per_field_and_excluded() {
+        let user = User {
+            username: "jj".into(),
+            email: "invalid email".into(),
+        };
+        let raw_contact_dto: RawContactDto = user.into();
+
+        //only validate is forwarded for username and nothing is forwarded for email
+        let json_string = serde_json::to_string(&raw_contact_dto).unwrap();
+        assert_eq!(json_string, r#"{"username":"jj","email":"invalid email"}"#);
+
+        let validation_errors = raw_contact_dto.validate().unwrap_err();
+        assert!(validation_errors.0.contains_key("username"));
+        assert!(!validation_errors.0.contains_key("email"));
+    }
+}
@@ -0,0 +1,66 @@
+#[cfg(test)]
+mod test_dto_groups {
+    use dto_mapper::DtoMapper;
+
+    #[derive(DtoMapper, Debug, Default, Clone)]
+    #[mapper( dto="PublicDto" , no_builder=true , include_groups=["public"] , derive=(Debug, Clone, PartialEq) )]
+    #[mapper( dto="AdminDto" , no_builder=true , include_groups=["public", "admin"] , map=[ ("id:account_id",true) ] ,
+        derive=(Debug, Clone, PartialEq)
+    )]
+    #[mapper( dto="NoAuditDto" , no_builder=true , exclude_groups=["audit"] , derive=(Debug, Clone, PartialEq) )]
+    struct Account {
+        id: u32,
+        #[mapper(groups("public", "admin"))]
+        name: String,
+        #[mapper(groups("admin"))]
+        email: String,
+        #[mapper(groups("audit"))]
+        created_by: String,
+    }
+
+    fn account() -> Account {
+        Account {
+            id: 7,
+            name: "Ayiti".into(),
+            email: "ayiti@mail.ht".into(),
+            created_by: "root".into(),
+        }
+    }
+
+    #[test]
+    fn test_include_groups() {
+        let public_dto: PublicDto = account().into();
+        assert_eq!(
+            PublicDto {
+                name: "Ayiti".into()
+            },
+            public_dto
+        );
+
+        let admin_dto: AdminDto = account().into();
+        assert_eq!(
+            AdminDto {
+                account_id: 7,
+                name: "Ayiti".into(),
+                email: "ayiti@mail.ht".into(),
+            },
+            admin_dto
+        );
+    }
+
+    #[test]
+    fn test_exclude_groups() {
+        let no_audit_dto: NoAuditDto = account().into();
+        assert_eq!(
+            NoAuditDto {
+                id: 7,
+                name: "Ayiti".into(),
+                email: "ayiti@mail.ht".into(),
+            },
+            no_audit_dto
+        );
+
+        let account: Account = no_audit_dto.into();
+        assert_eq!("", account.created_by);
+    }
+}
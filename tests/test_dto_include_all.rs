@@ -0,0 +1,54 @@
+#[cfg(test)]
+mod test_dto_include_all {
+    use dto_mapper::DtoMapper;
+
+    #[derive(DtoMapper, Debug, Default, Clone)]
+    #[mapper( dto="UserDto" , no_builder=true , include_all=true , ignore=["password"] ,
+        map=[ ("username:login",true) , ("age",false) ] ,
+        derive=(Debug, Clone, PartialEq)
+    )]
+    #[mapper( dto="FullUserDto" , no_builder=true , include_all=true , allow_sensitive=["password"] ,
+        derive=(Debug, Clone, PartialEq)
+    )]
+    struct User {
+        username: String,
+        #[mapper(sensitive)]
+        password: String,
+        email: String,
+        age: u8,
+    }
+
+    fn user() -> User {
+        User {
+            username: "dessalines".into(),
+            password: "1804".into(),
+            email: "dessalines@mail.ht".into(),
+            age: 46,
+        }
+    }
+
+    #[test]
+    fn test_include_all_with_renames_and_exclusions() {
+        let user_dto: UserDto = user().into();
+        assert_eq!(
+            UserDto {
+                login: "dessalines".into(),
+                email: "dessalines@mail.ht".into(),
+                age: Some(46),
+            },
+            user_dto
+        );
+
+        let user: User = user_dto.into();
+        assert_eq!("dessalines", user.username);
+        assert_eq!("", user.password);
+        assert_eq!(46, user.age);
+    }
+
+    #[test]
+    fn test_include_all_without_map_nor_ignore() {
+        let full_user_dto: FullUserDto = user().into();
+        assert_eq!("1804", full_user_dto.password);
+        assert_eq!("dessalines@mail.ht", full_user_dto.email);
+    }
+}
@@ -0,0 +1,56 @@
+#[cfg(test)]
+mod test_dto_join {
+    use dto_mapper::DtoMapper;
+
+    #[derive(DtoMapper, Debug, Default, PartialEq)]
+    #[mapper( dto="LoginDto" , no_builder=true , map=[ ("username",true) , ("password",true) ] )]
+    #[mapper( dto="ProfileDto" , no_builder=true ,
+        map=[ ("email:mail",true) , ("nickname",true, unwrap_or="String::new()") ]
+    )]
+    #[mapper(split=["LoginDto", "ProfileDto"])]
+    #[mapper(join=["LoginDto", "ProfileDto"], fill=["login_count"])]
+    struct User {
+        username: String,
+        password: String,
+        email: String,
+        nickname: Option<String>,
+        login_count: u32,
+    }
+
+    #[test]
+    fn test_join_from_parts() {
+        let login = LoginDto {
+            username: "dessalines".into(),
+            password: "1804".into(),
+        };
+        let profile = ProfileDto {
+            mail: "dessalines@mail.ht".into(),
+            nickname: "papa".into(),
+        };
+        assert_eq!(
+            User {
+                username: "dessalines".into(),
+                password: "1804".into(),
+                email: "dessalines@mail.ht".into(),
+                nickname: Some("papa".into()),
+                login_count: 0,
+            },
+            User::from_parts(login, profile)
+        );
+    }
+
+    #[test]
+    fn test_join_after_split() {
+        let user = User {
+            username: "dessalines".into(),
+            password: "1804".into(),
+            email: "dessalines@mail.ht".into(),
+            nickname: Some("papa".into()),
+            login_count: 0,
+        };
+        let (login, profile) = user.into_parts();
+        let user = User::from_parts(login, profile);
+        assert_eq!("dessalines", user.username);
+        assert_eq!(Some("papa".into()), user.nickname);
+    }
+}
@@ -0,0 +1,46 @@
+#[cfg(test)]
+mod test_dto_option {
+    use dto_mapper::DtoMapper;
+
+    type MaybeName = Option<String>;
+
+    #[derive(DtoMapper, Debug, Default, Clone)]
+    #[mapper( dto="PersonDto" , no_builder=true , getters=true ,
+        map=[ ("nickname",false) , ("middle_name",false) , ("maiden_name",false) , ("age",false) , ("code",false) ]
+    )]
+    struct Person {
+        nickname: std::option::Option<String>,
+        middle_name: ::core::option::Option<String>,
+        #[mapper(optional)]
+        maiden_name: MaybeName,
+        age: Option<u8>,
+        #[mapper(not_optional)]
+        code: Option<u8>,
+    }
+
+    #[test]
+    fn test_qualified_and_aliased_options_are_not_wrapped_twice() {
+        let person = Person {
+            nickname: Some("Toto".into()),
+            middle_name: None,
+            maiden_name: Some("Lamour".into()),
+            age: Some(31),
+            code: None,
+        };
+        let person_dto: PersonDto = person.into();
+
+        let nickname: &Option<String> = person_dto.nickname();
+        let maiden_name: &Option<String> = person_dto.maiden_name();
+        assert_eq!(&Some("Toto".to_string()), nickname);
+        assert_eq!(&None, person_dto.middle_name());
+        assert_eq!(&Some("Lamour".to_string()), maiden_name);
+        //Option<u8> is a Copy type
+        assert_eq!(Some(31), person_dto.age());
+        //not_optional keeps the Option as a plain value which gets wrapped
+        assert_eq!(Some(None), person_dto.code());
+
+        let person: Person = person_dto.into();
+        assert_eq!(Some("Lamour".to_string()), person.maiden_name);
+        assert_eq!(Some(31), person.age);
+    }
+}
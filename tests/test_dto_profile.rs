@@ -0,0 +1,88 @@
+#[cfg(test)]
+mod test_dto_profile {
+    use dto_mapper::DtoMapper;
+
+    #[derive(DtoMapper, Debug, Default, Clone)]
+    #[mapper_defaults(no_builder = true)]
+    #[mapper(profile = ["crud", "summary"], profile_file = "tests/profiles/dto_profiles.rs")]
+    struct User {
+        id: u32,
+        name: String,
+        password_hash: String,
+        created_at: u64,
+    }
+
+    //Post has neither password_hash nor deleted_at which are ignored by the crud profile
+    #[derive(DtoMapper, Debug, Default, Clone)]
+    #[mapper_defaults(no_builder = true)]
+    #[mapper(profile = ["crud", "summary"], profile_file = "tests/profiles/dto_profiles.rs")]
+    struct Post {
+        id: u32,
+        title: String,
+        created_at: u64,
+    }
+
+    #[test]
+    fn test_profile_dtos_are_named_after_the_struct() {
+        let user = User {
+            id: 1,
+            name: "Sanite".into(),
+            password_hash: "x1804".into(),
+            created_at: 1804,
+        };
+        assert_eq!(
+            UserResponse {
+                id: 1,
+                name: "Sanite".into(),
+                created_at: 1804,
+            },
+            user.clone().into()
+        );
+        assert_eq!(
+            UserCreate {
+                name: "Sanite".into(),
+                password_hash: "x1804".into(),
+            },
+            user.clone().into()
+        );
+        assert_eq!(
+            UserUpdate {
+                name: Some("Sanite".into()),
+                password_hash: Some("x1804".into()),
+                created_at: Some(1804),
+            },
+            user.clone().into()
+        );
+        assert_eq!(
+            UserSummary {
+                id: 1,
+                name: "Sanite".into(),
+            },
+            user.into()
+        );
+    }
+
+    #[test]
+    fn test_profile_tolerates_missing_fields() {
+        let post = Post {
+            id: 2,
+            title: "Vertieres".into(),
+            created_at: 1803,
+        };
+        assert_eq!(
+            PostResponse {
+                id: 2,
+                title: "Vertieres".into(),
+                created_at: 1803,
+            },
+            post.clone().into()
+        );
+        assert_eq!(
+            PostSummary {
+                id: 2,
+                title: "Vertieres".into(),
+            },
+            post.into()
+        );
+    }
+}
@@ -0,0 +1,43 @@
+#[cfg(test)]
+mod test_dto_redact {
+    use dto_mapper::DtoMapper;
+
+    #[derive(DtoMapper, Debug, Default, Clone)]
+    #[mapper( dto="LoginDto" , no_builder=true , redact=["password"] ,
+        map=[ ("username",true) , ("password",true) , ("token:session",true, redact) ] ,
+        new_fields=[( "secret: String", "String::from(\"s3cr3t\")", redact )]
+    )]
+    #[mapper( dto="ProfileDto" , no_builder=true , derive=(Debug, Clone) , map=[ ("username",true) ] )]
+    struct User {
+        username: String,
+        password: String,
+        token: String,
+    }
+
+    fn user() -> User {
+        User {
+            username: "dessalines".into(),
+            password: "1804".into(),
+            token: "abc".into(),
+        }
+    }
+
+    #[test]
+    fn test_redacted_fields_are_hidden_from_debug() {
+        let login_dto: LoginDto = user().into();
+        assert_eq!(
+            r#"LoginDto { username: "dessalines", password: <redacted>, session: <redacted>, secret: <redacted> }"#,
+            format!("{:?}", login_dto)
+        );
+        assert_eq!("1804", login_dto.password);
+    }
+
+    #[test]
+    fn test_dto_without_redacted_fields_keeps_derived_debug() {
+        let profile_dto: ProfileDto = user().into();
+        assert_eq!(
+            r#"ProfileDto { username: "dessalines" }"#,
+            format!("{:?}", profile_dto)
+        );
+    }
+}
@@ -0,0 +1,65 @@
+#[cfg(test)]
+mod test_dto_remote {
+    //stands for a crate whose structs can't derive DtoMapper
+    mod billing {
+        #[derive(Debug, Default, PartialEq)]
+        pub struct Invoice {
+            pub id: u64,
+            pub customer: String,
+            pub amount: i64,
+            pub note: Option<String>,
+        }
+    }
+    use billing::Invoice;
+
+    //the mirror struct isn't generated, so it can have the name of the remote struct
+    dto_mapper::remote!(
+        #[mapper(remote = "billing::Invoice")]
+        #[mapper( dto="InvoiceDto" , no_builder=true , derive=(Debug, PartialEq) ,
+            map=[ ("id",true) , ("customer:client",true) , ("note",true, unwrap_or="String::new()") ]
+        )]
+        #[mapper( dto="AmountDto" , no_builder=true , derive=(Debug, PartialEq) , map=[ ("amount",false) ] )]
+        struct Invoice {
+            id: u64,
+            customer: String,
+            amount: i64,
+            note: Option<String>,
+        }
+    );
+
+    fn invoice() -> Invoice {
+        Invoice {
+            id: 7,
+            customer: "dessalines".into(),
+            amount: 1804,
+            note: None,
+        }
+    }
+
+    #[test]
+    fn test_remote_into_dto() {
+        let invoice_dto: InvoiceDto = invoice().into();
+        assert_eq!(
+            InvoiceDto {
+                id: 7,
+                client: "dessalines".into(),
+                note: String::new(),
+            },
+            invoice_dto
+        );
+        let amount_dto: AmountDto = invoice().into();
+        assert_eq!(AmountDto { amount: Some(1804) }, amount_dto);
+    }
+
+    #[test]
+    fn test_dto_into_remote() {
+        let invoice: Invoice = AmountDto { amount: Some(1804) }.into();
+        assert_eq!(
+            Invoice {
+                amount: 1804,
+                ..Invoice::default()
+            },
+            invoice
+        );
+    }
+}
@@ -0,0 +1,87 @@
+#[cfg(test)]
+mod test_dto_selectors {
+    use dto_mapper::DtoMapper;
+    use std::marker::PhantomData;
+
+    #[derive(Debug, Default, Clone, PartialEq)]
+    pub struct Secret<T>(T);
+
+    #[derive(DtoMapper, Debug, Default, Clone)]
+    #[mapper( dto="PublicDto" , no_builder=true , ignore=["internal_*", "*_hash"] , ignore_types=["Secret<_>", "PhantomData<_>"] ,
+        derive=(Debug, Clone, PartialEq)
+    )]
+    #[mapper( dto="TextDto" , no_builder=true , include_types=["String"] , map=[ ("id",true) ] ,
+        derive=(Debug, Clone, PartialEq)
+    )]
+    #[mapper( dto="InternalDto" , no_builder=true , map=[ ("internal_*",false) ] ,
+        derive=(Debug, Clone, PartialEq)
+    )]
+    struct Account {
+        id: u32,
+        name: String,
+        email: String,
+        password_hash: String,
+        internal_note: String,
+        internal_rank: u8,
+        api_key: Secret<String>,
+        marker: std::marker::PhantomData<u8>,
+    }
+
+    fn account() -> Account {
+        Account {
+            id: 7,
+            name: "Ayiti".into(),
+            email: "ayiti@mail.ht".into(),
+            password_hash: "x1804".into(),
+            internal_note: "vip".into(),
+            internal_rank: 1,
+            api_key: Secret("key".into()),
+            marker: PhantomData,
+        }
+    }
+
+    #[test]
+    fn test_ignore_glob_patterns_and_types() {
+        let public_dto: PublicDto = account().into();
+        assert_eq!(
+            PublicDto {
+                id: 7,
+                name: "Ayiti".into(),
+                email: "ayiti@mail.ht".into(),
+            },
+            public_dto
+        );
+
+        //excluded fields are set to their default value when converting the dto
+        let account: Account = public_dto.into();
+        assert_eq!(Secret(String::new()), account.api_key);
+        assert_eq!("", account.password_hash);
+    }
+
+    #[test]
+    fn test_include_types_with_map() {
+        let text_dto: TextDto = account().into();
+        assert_eq!(
+            TextDto {
+                id: 7,
+                name: "Ayiti".into(),
+                email: "ayiti@mail.ht".into(),
+                password_hash: "x1804".into(),
+                internal_note: "vip".into(),
+            },
+            text_dto
+        );
+    }
+
+    #[test]
+    fn test_map_glob_pattern() {
+        let internal_dto: InternalDto = account().into();
+        assert_eq!(
+            InternalDto {
+                internal_note: Some("vip".into()),
+                internal_rank: Some(1),
+            },
+            internal_dto
+        );
+    }
+}
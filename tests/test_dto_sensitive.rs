@@ -0,0 +1,34 @@
+#[cfg(test)]
+mod test_dto_sensitive {
+    use dto_mapper::DtoMapper;
+
+    //a dto including password or api_token without allowing it fails to compile
+    #[derive(DtoMapper, Debug, Default, Clone)]
+    #[mapper( dto="LoginDto" , no_builder=true , allow_sensitive=["password"] , map=[ ("username",true), ("password",true) ] )]
+    #[mapper( dto="ProfileDto" , no_builder=true , sensitive=["*token*", "*secret*"] , ignore=["password", "api_token"] )]
+    #[mapper( dto="ApiDto" , no_builder=true , sensitive=["*token*"] , allow_sensitive=["api_token"] , map=[ ("api_token",true) ] )]
+    struct User {
+        username: String,
+        #[mapper(sensitive)]
+        password: String,
+        email: String,
+        api_token: String,
+    }
+
+    #[test]
+    fn test_allowed_sensitive_fields() {
+        let user = User {
+            username: "dessalines".into(),
+            password: "hello123".into(),
+            email: "dessalines@mail.ht".into(),
+            api_token: "XXXXXXXX".into(),
+        };
+        let login_dto: LoginDto = user.clone().into();
+        let profile_dto: ProfileDto = user.clone().into();
+        let api_dto: ApiDto = user.into();
+
+        assert_eq!("hello123", login_dto.password);
+        assert_eq!("dessalines@mail.ht", profile_dto.email);
+        assert_eq!("XXXXXXXX", api_dto.api_token);
+    }
+}
@@ -0,0 +1,50 @@
+#[cfg(test)]
+mod test_dto_split {
+    use dto_mapper::DtoMapper;
+
+    //User isn't Clone, each field is moved into a single dto
+    #[derive(DtoMapper, Default)]
+    #[mapper( dto="LoginDto" , no_builder=true , derive=(Debug, PartialEq) ,
+        map=[ ("username",true) , ("password",true) ]
+    )]
+    #[mapper( dto="ProfileDto" , no_builder=true , derive=(Debug, PartialEq) ,
+        map=[ ("email:mail",false) , ("nickname",true, unwrap_or="String::from(\"anonymous\")") ] ,
+        new_fields=[( "username_length: usize", "self.username.len()" )]
+    )]
+    #[mapper(split=["LoginDto", "ProfileDto"])]
+    struct User {
+        username: String,
+        password: String,
+        email: String,
+        nickname: Option<String>,
+        #[allow(dead_code)]
+        age: u8,
+    }
+
+    #[test]
+    fn test_split_into_parts() {
+        let user = User {
+            username: "dessalines".into(),
+            password: "1804".into(),
+            email: "dessalines@mail.ht".into(),
+            nickname: None,
+            age: 46,
+        };
+        let (login, profile) = user.into_parts();
+        assert_eq!(
+            LoginDto {
+                username: "dessalines".into(),
+                password: "1804".into(),
+            },
+            login
+        );
+        assert_eq!(
+            ProfileDto {
+                mail: Some("dessalines@mail.ht".into()),
+                nickname: "anonymous".into(),
+                username_length: 10,
+            },
+            profile
+        );
+    }
+}
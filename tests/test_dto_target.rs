@@ -0,0 +1,72 @@
+#[cfg(test)]
+mod test_dto_target {
+    use dto_mapper::DtoMapper;
+
+    //types of an api client which dto_mapper doesn't generate
+    mod api {
+        #[derive(Debug, Default, PartialEq)]
+        pub struct LoginPayload {
+            pub login: String,
+            pub password: Option<String>,
+            pub client: String,
+        }
+
+        #[derive(Debug, Default, PartialEq)]
+        pub struct Contact {
+            pub email: String,
+        }
+    }
+
+    #[derive(DtoMapper, Debug, Default, Clone, PartialEq)]
+    #[mapper( target="api::LoginPayload" , generate=false ,
+        map=[ ("username:login",true) , ("password",false) ] ,
+        new_fields=[( "client: String", "String::from(\"web\")" )]
+    )]
+    #[mapper( target="api::Contact" , generate=false , map=[ ("email",true, unwrap_or="String::new()") ] )]
+    struct User {
+        username: String,
+        password: String,
+        email: Option<String>,
+    }
+
+    fn user() -> User {
+        User {
+            username: "dessalines".into(),
+            password: "1804".into(),
+            email: None,
+        }
+    }
+
+    #[test]
+    fn test_struct_into_target() {
+        let payload: api::LoginPayload = user().into();
+        assert_eq!(
+            api::LoginPayload {
+                login: "dessalines".into(),
+                password: Some("1804".into()),
+                client: "web".into(),
+            },
+            payload
+        );
+
+        let contact: api::Contact = user().into();
+        assert_eq!("", contact.email);
+    }
+
+    #[test]
+    fn test_target_into_struct() {
+        let payload = api::LoginPayload {
+            login: "toussaint".into(),
+            password: None,
+            client: "web".into(),
+        };
+        let user: User = payload.into();
+        assert_eq!(
+            User {
+                username: "toussaint".into(),
+                ..User::default()
+            },
+            user
+        );
+    }
+}
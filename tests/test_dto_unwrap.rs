@@ -0,0 +1,63 @@
+#[cfg(test)]
+mod test_dto_unwrap {
+    use dto_mapper::DtoMapper;
+    use std::convert::TryInto;
+
+    #[derive(DtoMapper, Debug, Default, Clone, PartialEq)]
+    #[mapper( dto="NameDto" , no_builder=true , derive=(Debug, PartialEq) ,
+        map=[ ("firstname",true) , ("middle_name",true, unwrap) ] ,
+        new_fields=[( "initial: char", "self.lastname.chars().next().unwrap_or_default()" )]
+    )]
+    #[mapper( dto="AgeDto" , no_builder=true , map=[ ("age",true, unwrap_or="18") ] )]
+    struct Person {
+        firstname: String,
+        middle_name: Option<String>,
+        lastname: String,
+        age: Option<u8>,
+    }
+
+    #[test]
+    fn test_unwrapped_field_is_converted_with_try_from() {
+        let person = Person {
+            firstname: "Toussaint".into(),
+            middle_name: Some("Louverture".into()),
+            lastname: "Breda".into(),
+            age: None,
+        };
+        let name_dto: NameDto = person.clone().try_into().unwrap();
+        assert_eq!(
+            NameDto {
+                firstname: "Toussaint".into(),
+                middle_name: "Louverture".into(),
+                initial: 'B',
+            },
+            name_dto
+        );
+
+        let person_from_dto: Person = name_dto.into();
+        assert_eq!(Some("Louverture".to_string()), person_from_dto.middle_name);
+    }
+
+    #[test]
+    fn test_unwrapped_field_missing_value() {
+        let person = Person {
+            firstname: "Toussaint".into(),
+            ..Person::default()
+        };
+        let error = NameDto::try_from(person).unwrap_err();
+        assert_eq!(NameDtoConversionError::MissingField("middle_name"), error);
+        assert_eq!(
+            "missing value for field `middle_name` of NameDto",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_unwrapped_field_with_fallback() {
+        let age_dto: AgeDto = Person::default().into();
+        assert_eq!(18, age_dto.age);
+
+        let person: Person = AgeDto { age: 40 }.into();
+        assert_eq!(Some(40), person.age);
+    }
+}
@@ -0,0 +1,75 @@
+#[cfg(all(test, feature = "zeroize"))]
+mod test_dto_zeroize {
+    use dto_mapper::DtoMapper;
+    use std::{cell::Cell, rc::Rc};
+    use zeroize::Zeroize;
+
+    #[derive(DtoMapper, Debug, Default, Clone)]
+    #[mapper( dto="LoginDto" , no_builder=true , zeroize=["password", "otp"] , setters=true ,
+        map=[ ("username",true) , ("password",true) , ("otp",false) , ("pin",true, unwrap) ] ,
+        derive=(Debug, Clone)
+    )]
+    struct User {
+        username: String,
+        password: String,
+        otp: String,
+        pin: Option<u16>,
+    }
+
+    #[test]
+    fn test_zeroized_dto_converts_back_into_struct() {
+        let user = User {
+            username: "dessalines".into(),
+            password: "1804".into(),
+            otp: "000111".into(),
+            pin: Some(1234),
+        };
+        let login_dto: LoginDto = user.try_into().unwrap();
+        let login_dto = login_dto.with_password("1805");
+
+        let user: User = login_dto.into();
+        assert_eq!("dessalines", user.username);
+        assert_eq!("1805", user.password);
+        assert_eq!("000111", user.otp);
+        assert_eq!(Some(1234), user.pin);
+    }
+
+    //records the call to zeroize so the test doesn't read the dto once it is dropped
+    #[derive(Default)]
+    struct CardNumber {
+        digits: [u8; 4],
+        wiped: Rc<Cell<bool>>,
+    }
+
+    impl Zeroize for CardNumber {
+        fn zeroize(&mut self) {
+            self.digits.zeroize();
+            self.wiped.set(true);
+        }
+    }
+
+    #[derive(DtoMapper, Default)]
+    #[mapper( dto="CardDto" , no_builder=true , zeroize=["number"] , ignore=["holder"] )]
+    struct Card {
+        number: CardNumber,
+        #[allow(dead_code)]
+        holder: String,
+    }
+
+    #[test]
+    fn test_zeroized_fields_are_wiped_on_drop() {
+        let wiped = Rc::new(Cell::new(false));
+        let card = Card {
+            number: CardNumber {
+                digits: [4, 5, 6, 7],
+                wiped: wiped.clone(),
+            },
+            ..Card::default()
+        };
+        let card_dto: CardDto = card.into();
+        assert_eq!([4, 5, 6, 7], card_dto.number.digits);
+        assert!(!wiped.get());
+        drop(card_dto);
+        assert!(wiped.get());
+    }
+}